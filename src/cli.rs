@@ -2,7 +2,8 @@
 //!
 //! Uses clap with derive macros for simple, declarative CLI definition.
 
-use clap::Parser;
+use crate::config::Profile;
+use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
 /// Auto-detect technologies and generate pre-commit configuration files.
@@ -12,6 +13,10 @@ use std::path::PathBuf;
 #[command(version)]
 #[command(about = "Auto-detect technologies and generate pre-commit configuration files.", long_about = None)]
 pub struct Cli {
+    /// Subcommand to run instead of the default detect-and-generate flow
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+
     /// Enable interactive mode for customizing configuration
     #[arg(short, long)]
     pub interactive: bool,
@@ -20,9 +25,44 @@ pub struct Cli {
     #[arg(long, conflicts_with = "interactive")]
     pub generate_only: bool,
 
+    /// Verify that the committed .pre-commit-config.yaml matches what
+    /// detection would generate, printing a diff and exiting non-zero on
+    /// drift, instead of writing the file
+    #[arg(long, conflicts_with_all = ["interactive", "generate_only"])]
+    pub check: bool,
+
     /// Path to analyze (default: current directory)
     #[arg(long, default_value = ".")]
     pub path: PathBuf,
+
+    /// Path to a declarative config file (defaults to searching `--path` for
+    /// prec-templ.toml or .prec-templ.yaml)
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Query upstream hook repos for their latest release tag before
+    /// rendering, instead of using the offline pinned revisions
+    #[arg(long)]
+    pub autoupdate: bool,
+
+    /// Generation profile layering stricter or looser hook choices on top of
+    /// auto-detection (interactive mode prompts for one if omitted)
+    #[arg(long, value_enum)]
+    pub profile: Option<Profile>,
+
+    /// Run pre-commit only on files changed since `--since` (or HEAD),
+    /// instead of `--all-files`
+    #[arg(long, conflicts_with_all = ["interactive", "generate_only"])]
+    pub changed_only: bool,
+
+    /// Git ref to diff against when `--changed-only` is set (defaults to HEAD)
+    #[arg(long, requires = "changed_only")]
+    pub since: Option<String>,
+
+    /// Overwrite an existing .pre-commit-config.yaml entirely instead of
+    /// merging generated hooks into it
+    #[arg(long, conflicts_with_all = ["interactive", "check"])]
+    pub force: bool,
 }
 
 impl Cli {
@@ -32,6 +72,15 @@ impl Cli {
     }
 }
 
+/// Subcommands that replace the default detect-and-generate flow entirely.
+#[derive(Subcommand, Debug)]
+pub enum Commands {
+    /// Print a JSON Schema document for the detected-config model and the
+    /// `.pre-commit-template.toml` override file, for editor validation and
+    /// autocomplete
+    Schema,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +117,125 @@ mod tests {
         assert!(cli.generate_only);
         assert!(!cli.interactive);
     }
+
+    #[test]
+    fn test_config_flag() {
+        let cli = Cli::parse_from(["prec-templ", "--config", "custom.toml"]);
+        assert_eq!(cli.config, Some(PathBuf::from("custom.toml")));
+    }
+
+    #[test]
+    fn test_config_flag_defaults_to_none() {
+        let cli = Cli::parse_from(["prec-templ"]);
+        assert_eq!(cli.config, None);
+    }
+
+    #[test]
+    fn test_autoupdate_flag() {
+        let cli = Cli::parse_from(["prec-templ", "--autoupdate"]);
+        assert!(cli.autoupdate);
+    }
+
+    #[test]
+    fn test_autoupdate_defaults_to_false() {
+        let cli = Cli::parse_from(["prec-templ"]);
+        assert!(!cli.autoupdate);
+    }
+
+    #[test]
+    fn test_check_flag() {
+        let cli = Cli::parse_from(["prec-templ", "--check"]);
+        assert!(cli.check);
+    }
+
+    #[test]
+    fn test_check_defaults_to_false() {
+        let cli = Cli::parse_from(["prec-templ"]);
+        assert!(!cli.check);
+    }
+
+    #[test]
+    fn test_check_conflicts_with_interactive() {
+        let result = Cli::try_parse_from(["prec-templ", "--check", "--interactive"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_conflicts_with_generate_only() {
+        let result = Cli::try_parse_from(["prec-templ", "--check", "--generate-only"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_profile_flag() {
+        let cli = Cli::parse_from(["prec-templ", "--profile", "strict"]);
+        assert_eq!(cli.profile, Some(Profile::Strict));
+    }
+
+    #[test]
+    fn test_profile_defaults_to_none() {
+        let cli = Cli::parse_from(["prec-templ"]);
+        assert_eq!(cli.profile, None);
+    }
+
+    #[test]
+    fn test_profile_rejects_unknown_value() {
+        let result = Cli::try_parse_from(["prec-templ", "--profile", "nonsense"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_changed_only_flag() {
+        let cli = Cli::parse_from(["prec-templ", "--changed-only"]);
+        assert!(cli.changed_only);
+        assert_eq!(cli.since, None);
+    }
+
+    #[test]
+    fn test_since_flag_requires_changed_only() {
+        let result = Cli::try_parse_from(["prec-templ", "--since", "main"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_since_flag_with_changed_only() {
+        let cli = Cli::parse_from(["prec-templ", "--changed-only", "--since", "main"]);
+        assert_eq!(cli.since, Some("main".to_string()));
+    }
+
+    #[test]
+    fn test_changed_only_conflicts_with_interactive() {
+        let result = Cli::try_parse_from(["prec-templ", "--changed-only", "--interactive"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_force_flag() {
+        let cli = Cli::parse_from(["prec-templ", "--force"]);
+        assert!(cli.force);
+    }
+
+    #[test]
+    fn test_force_defaults_to_false() {
+        let cli = Cli::parse_from(["prec-templ"]);
+        assert!(!cli.force);
+    }
+
+    #[test]
+    fn test_force_conflicts_with_check() {
+        let result = Cli::try_parse_from(["prec-templ", "--force", "--check"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_schema_subcommand() {
+        let cli = Cli::parse_from(["prec-templ", "schema"]);
+        assert!(matches!(cli.command, Some(Commands::Schema)));
+    }
+
+    #[test]
+    fn test_no_subcommand_defaults_to_none() {
+        let cli = Cli::parse_from(["prec-templ"]);
+        assert!(cli.command.is_none());
+    }
 }
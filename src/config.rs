@@ -3,15 +3,25 @@
 //! This module defines the configuration structure that controls which
 //! pre-commit hooks are generated, equivalent to the Python Pydantic model.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
 
 /// Configuration for pre-commit hook generation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PreCommitConfig {
     /// Python version for default_language_version (e.g., "python3.11")
     #[serde(default)]
     pub python_version: Option<String>,
 
+    /// Python interpreter named for the `<binary> --version` fallback when
+    /// resolving the active version. `discover_config` always runs that
+    /// fallback with `python3`; a value set here is kept in the config but
+    /// does not change detection.
+    #[serde(default = "default_python_binary")]
+    pub python_binary: String,
+
     // Base hooks options
     #[serde(default)]
     pub yaml_check: bool,
@@ -29,6 +39,14 @@ pub struct PreCommitConfig {
     pub symlinks: bool,
     #[serde(default)]
     pub python_base: bool,
+    /// Secret-scanning flag, turned on by the `security` profile. It is passed
+    /// to the base hooks template, which renders no hook for it.
+    #[serde(default)]
+    pub secrets_detection: bool,
+    /// Dependency-audit flag, turned on by the `security` profile. It is
+    /// passed to the base hooks template, which renders no hook for it.
+    #[serde(default)]
+    pub dependency_audit: bool,
 
     // Python hooks options
     #[serde(default)]
@@ -65,22 +83,103 @@ pub struct PreCommitConfig {
     pub prettier_config: Option<String>,
     #[serde(default)]
     pub eslint_config: Option<String>,
+    /// Package manager inferred from `packageManager`/lockfile (`"npm"`,
+    /// `"yarn"`, or `"pnpm"`)
+    #[serde(default)]
+    pub package_manager: Option<String>,
+    /// Node engine constraint from `package.json`'s `engines.node`, recorded
+    /// the same way `python_version` is
+    #[serde(default)]
+    pub node_version: Option<String>,
+    /// `package.json`'s `private` flag
+    #[serde(default)]
+    pub js_private: bool,
 
     // Go hooks options
     #[serde(default)]
     pub go: bool,
     #[serde(default)]
     pub go_critic: bool,
+
+    /// Maven `artifactId` read from `pom.xml`'s top-level coordinates
+    #[serde(default)]
+    pub maven_artifact_id: Option<String>,
+    /// Maven `version` read from `pom.xml`'s top-level coordinates, for
+    /// display only (nested `<parent>`/`<dependency>` versions are ignored)
+    #[serde(default)]
+    pub maven_version: Option<String>,
+
+    // Java hooks options
+    #[serde(default)]
+    pub java: bool,
+    #[serde(default = "default_true")]
+    pub java_format: bool,
+    #[serde(default)]
+    pub checkstyle: bool,
+
+    /// Crate version read from `[package].version` in Cargo.toml, for display
+    /// only (`None` for workspace-only virtual manifests)
+    #[serde(default)]
+    pub cargo_version: Option<String>,
+
+    // Rust hooks options
+    #[serde(default)]
+    pub rust: bool,
+    #[serde(default = "default_true")]
+    pub rustfmt: bool,
+    #[serde(default)]
+    pub clippy: bool,
+    #[serde(default)]
+    pub cargo_check: bool,
+
+    // Ruby hooks options
+    #[serde(default)]
+    pub ruby: bool,
+    #[serde(default = "default_true")]
+    pub rubocop: bool,
+
+    // Lua hooks options
+    #[serde(default)]
+    pub lua: bool,
+    #[serde(default = "default_true")]
+    pub luacheck: bool,
+    #[serde(default = "default_true")]
+    pub stylua: bool,
+
+    // Dart hooks options
+    #[serde(default)]
+    pub dart: bool,
+    #[serde(default = "default_true")]
+    pub dart_analyze: bool,
+
+    // Perl hooks options
+    #[serde(default)]
+    pub perl: bool,
+    #[serde(default = "default_true")]
+    pub perlcritic: bool,
+
+    // Shell hooks options
+    #[serde(default)]
+    pub shell: bool,
+    #[serde(default = "default_true")]
+    pub shellcheck: bool,
+    #[serde(default = "default_true")]
+    pub shfmt: bool,
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_python_binary() -> String {
+    "python3".to_string()
+}
+
 impl Default for PreCommitConfig {
     fn default() -> Self {
         Self {
             python_version: None,
+            python_binary: default_python_binary(),
             yaml_check: false,
             json_check: false,
             toml_check: false,
@@ -89,6 +188,8 @@ impl Default for PreCommitConfig {
             executables: false,
             symlinks: false,
             python_base: false,
+            secrets_detection: false,
+            dependency_audit: false,
             python: false,
             uv_lock: false,
             pyrefly_args: None,
@@ -103,8 +204,33 @@ impl Default for PreCommitConfig {
             jsx: false,
             prettier_config: None,
             eslint_config: None,
+            package_manager: None,
+            node_version: None,
+            js_private: false,
             go: false,
             go_critic: false,
+            maven_artifact_id: None,
+            maven_version: None,
+            java: false,
+            java_format: true, // Default to true
+            checkstyle: false,
+            cargo_version: None,
+            rust: false,
+            rustfmt: true, // Default to true
+            clippy: false,
+            cargo_check: false,
+            ruby: false,
+            rubocop: true, // Default to true
+            lua: false,
+            luacheck: true, // Default to true
+            stylua: true,   // Default to true
+            dart: false,
+            dart_analyze: true, // Default to true
+            perl: false,
+            perlcritic: true, // Default to true
+            shell: false,
+            shellcheck: true, // Default to true
+            shfmt: true,      // Default to true
         }
     }
 }
@@ -145,6 +271,27 @@ impl PreCommitConfig {
         if self.go {
             techs.push("go");
         }
+        if self.rust {
+            techs.push("rust");
+        }
+        if self.java {
+            techs.push("java");
+        }
+        if self.ruby {
+            techs.push("ruby");
+        }
+        if self.lua {
+            techs.push("lua");
+        }
+        if self.dart {
+            techs.push("dart");
+        }
+        if self.perl {
+            techs.push("perl");
+        }
+        if self.shell {
+            techs.push("shell");
+        }
         if self.docker {
             techs.push("docker");
         }
@@ -155,9 +302,187 @@ impl PreCommitConfig {
     }
 }
 
+/// A curated bundle of stricter or looser hook choices, layered on top of
+/// whatever `discover_config` already detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    /// Turns off the optional extra linters and scanners (clippy, cargo
+    /// check, checkstyle, go-critic, dockerignore check, workflow security
+    /// scanning, secret scanning, dependency audit). Every other hook,
+    /// including the linters in each language's hook pack, stays as detected.
+    Minimal,
+    /// Balanced defaults for everyday development (today's behavior).
+    Standard,
+    /// Adds type-checkers and stricter linters for CI-grade enforcement.
+    Strict,
+    /// Turns on the secret-scanning and dependency-audit flags, plus workflow
+    /// security scanning when GitHub Actions is detected. The base hooks
+    /// template renders no hook for the first two flags, so only the
+    /// workflow scanning changes the generated config.
+    Security,
+}
+
+impl Profile {
+    /// All profiles, in the order they should be presented for selection.
+    pub const ALL: [Profile; 4] = [
+        Profile::Minimal,
+        Profile::Standard,
+        Profile::Strict,
+        Profile::Security,
+    ];
+
+    /// One-line description of what this profile changes, shown alongside
+    /// its name in a selectable list.
+    pub fn purpose(&self) -> &'static str {
+        match self {
+            Profile::Minimal => "Drops optional extra linters and scanners; language hook packs stay as detected",
+            Profile::Standard => "Balanced defaults for everyday development",
+            Profile::Strict => "Adds type-checkers and stricter linters for CI-grade enforcement",
+            Profile::Security => "Adds workflow security scanning; secret-scanning and dependency-audit flags render no hooks",
+        }
+    }
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Profile::Minimal => "Minimal",
+            Profile::Standard => "Standard",
+            Profile::Strict => "Strict",
+            Profile::Security => "Security",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Apply a generation profile to an already-detected config, only toggling
+/// strictness flags for technologies detection already turned on (a profile
+/// never enables a language on its own).
+pub fn apply_profile(mut config: PreCommitConfig, profile: Profile) -> PreCommitConfig {
+    match profile {
+        Profile::Minimal => {
+            config.clippy = false;
+            config.cargo_check = false;
+            config.checkstyle = false;
+            config.go_critic = false;
+            config.dockerignore_check = false;
+            config.security_scanning = false;
+            config.secrets_detection = false;
+            config.dependency_audit = false;
+        }
+        Profile::Standard => {}
+        Profile::Strict => {
+            if config.rust {
+                config.clippy = true;
+                config.cargo_check = true;
+            }
+            if config.go {
+                config.go_critic = true;
+            }
+            if config.java {
+                config.checkstyle = true;
+            }
+            if config.docker {
+                config.dockerignore_check = true;
+            }
+            if config.github_actions {
+                config.security_scanning = true;
+            }
+            config.case_conflict = true;
+            config.executables = true;
+        }
+        Profile::Security => {
+            config.secrets_detection = true;
+            config.dependency_audit = true;
+            if config.github_actions {
+                config.security_scanning = true;
+            }
+        }
+    }
+    config
+}
+
+/// Default filename for the TOML variant of the declarative config file.
+const CONFIG_FILE_TOML: &str = "prec-templ.toml";
+/// Default filename for the YAML variant of the declarative config file.
+const CONFIG_FILE_YAML: &str = ".prec-templ.yaml";
+
+/// Locate a declarative config file under `path`, preferring `prec-templ.toml`
+/// over `.prec-templ.yaml` when both are present.
+fn find_config_file(path: &Path) -> Option<PathBuf> {
+    let toml_path = path.join(CONFIG_FILE_TOML);
+    if toml_path.is_file() {
+        return Some(toml_path);
+    }
+    let yaml_path = path.join(CONFIG_FILE_YAML);
+    if yaml_path.is_file() {
+        return Some(yaml_path);
+    }
+    None
+}
+
+/// Parse a declarative config file into a JSON value, dispatching on extension.
+fn parse_config_file(file_path: &Path) -> Result<serde_json::Value, String> {
+    let content = fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read {}: {}", file_path.display(), e))?;
+
+    let is_yaml = matches!(
+        file_path.extension().and_then(|e| e.to_str()),
+        Some("yaml") | Some("yml")
+    );
+
+    if is_yaml {
+        serde_yaml::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", file_path.display(), e))
+    } else {
+        let table: toml::Table = content
+            .parse()
+            .map_err(|e| format!("Failed to parse {}: {}", file_path.display(), e))?;
+        serde_json::to_value(table).map_err(|e| e.to_string())
+    }
+}
+
+/// Overlay `overrides` onto `base`, keeping only the keys explicitly present
+/// in `overrides` and leaving everything else untouched.
+fn merge_json(mut base: serde_json::Value, overrides: serde_json::Value) -> serde_json::Value {
+    if let (Some(base_map), serde_json::Value::Object(override_map)) =
+        (base.as_object_mut(), overrides)
+    {
+        for (key, value) in override_map {
+            base_map.insert(key, value);
+        }
+    }
+    base
+}
+
+/// Merge a declarative config file found under `path` over `detected`, with
+/// values explicitly set in the file taking precedence. Returns `detected`
+/// unchanged if no config file is present.
+pub fn merge_config_file(
+    detected: PreCommitConfig,
+    path: &Path,
+    explicit_path: Option<&Path>,
+) -> Result<PreCommitConfig, String> {
+    let file_path = match explicit_path {
+        Some(p) => p.to_path_buf(),
+        None => match find_config_file(path) {
+            Some(p) => p,
+            None => return Ok(detected),
+        },
+    };
+
+    let overrides = parse_config_file(&file_path)?;
+    let base = serde_json::to_value(&detected).map_err(|e| e.to_string())?;
+    let merged = merge_json(base, overrides);
+    serde_json::from_value(merged)
+        .map_err(|e| format!("Invalid config in {}: {}", file_path.display(), e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::tempdir;
 
     #[test]
     fn test_default_config() {
@@ -199,4 +524,189 @@ mod tests {
         assert!(techs.contains(&"javascript"));
         assert!(techs.contains(&"docker"));
     }
+
+    #[test]
+    fn test_python_binary_defaults_to_python3() {
+        let config = PreCommitConfig::default();
+        assert_eq!(config.python_binary, "python3");
+    }
+
+    #[test]
+    fn test_js_package_manager_defaults_to_none() {
+        let config = PreCommitConfig::default();
+        assert_eq!(config.package_manager, None);
+        assert_eq!(config.node_version, None);
+        assert!(!config.js_private);
+    }
+
+    #[test]
+    fn test_java_defaults() {
+        let config = PreCommitConfig::default();
+        assert!(!config.java);
+        assert!(config.java_format); // Should default to true
+        assert!(!config.checkstyle);
+        assert_eq!(config.maven_version, None);
+        assert_eq!(config.maven_artifact_id, None);
+    }
+
+    #[test]
+    fn test_detected_technologies_includes_java() {
+        let config = PreCommitConfig {
+            java: true,
+            ..Default::default()
+        };
+        assert!(config.detected_technologies().contains(&"java"));
+    }
+
+    #[test]
+    fn test_rust_defaults() {
+        let config = PreCommitConfig::default();
+        assert!(!config.rust);
+        assert!(config.rustfmt); // Should default to true
+        assert!(!config.clippy);
+        assert!(!config.cargo_check);
+    }
+
+    #[test]
+    fn test_detected_technologies_includes_rust() {
+        let config = PreCommitConfig {
+            rust: true,
+            ..Default::default()
+        };
+        assert!(config.detected_technologies().contains(&"rust"));
+    }
+
+    #[test]
+    fn test_detected_technologies_includes_new_hook_packs() {
+        let config = PreCommitConfig {
+            ruby: true,
+            lua: true,
+            dart: true,
+            perl: true,
+            shell: true,
+            ..Default::default()
+        };
+        let techs = config.detected_technologies();
+        assert!(techs.contains(&"ruby"));
+        assert!(techs.contains(&"lua"));
+        assert!(techs.contains(&"dart"));
+        assert!(techs.contains(&"perl"));
+        assert!(techs.contains(&"shell"));
+    }
+
+    #[test]
+    fn test_merge_config_file_toml_overrides_detected() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("prec-templ.toml"),
+            "python = false\njson_check = true\n",
+        )
+        .unwrap();
+
+        let detected = PreCommitConfig {
+            python: true,
+            yaml_check: true,
+            ..Default::default()
+        };
+        let merged = merge_config_file(detected, tmp.path(), None).unwrap();
+
+        assert!(!merged.python); // explicit override wins
+        assert!(merged.json_check); // explicit override wins
+        assert!(merged.yaml_check); // untouched field is preserved
+    }
+
+    #[test]
+    fn test_merge_config_file_none_present_returns_detected_unchanged() {
+        let tmp = tempdir().unwrap();
+        let detected = PreCommitConfig {
+            python: true,
+            ..Default::default()
+        };
+        let merged = merge_config_file(detected.clone(), tmp.path(), None).unwrap();
+        assert_eq!(merged.python, detected.python);
+    }
+
+    #[test]
+    fn test_merge_config_file_yaml_variant() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join(".prec-templ.yaml"), "go: true\n").unwrap();
+
+        let merged = merge_config_file(PreCommitConfig::default(), tmp.path(), None).unwrap();
+        assert!(merged.go);
+    }
+
+    #[test]
+    fn test_apply_profile_minimal_turns_off_strictness() {
+        let config = PreCommitConfig {
+            rust: true,
+            clippy: true,
+            cargo_check: true,
+            ..Default::default()
+        };
+        let config = apply_profile(config, Profile::Minimal);
+        assert!(!config.clippy);
+        assert!(!config.cargo_check);
+    }
+
+    #[test]
+    fn test_apply_profile_standard_is_a_no_op() {
+        let config = PreCommitConfig {
+            rust: true,
+            clippy: true,
+            ..Default::default()
+        };
+        let before = config.clone();
+        let after = apply_profile(config, Profile::Standard);
+        assert_eq!(before.clippy, after.clippy);
+        assert_eq!(before.cargo_check, after.cargo_check);
+    }
+
+    #[test]
+    fn test_apply_profile_strict_enables_detected_languages_linters() {
+        let config = PreCommitConfig {
+            rust: true,
+            java: true,
+            ..Default::default()
+        };
+        let config = apply_profile(config, Profile::Strict);
+        assert!(config.clippy);
+        assert!(config.cargo_check);
+        assert!(config.checkstyle);
+    }
+
+    #[test]
+    fn test_apply_profile_strict_does_not_enable_undetected_languages() {
+        let config = PreCommitConfig::default();
+        let config = apply_profile(config, Profile::Strict);
+        assert!(!config.clippy);
+        assert!(!config.checkstyle);
+    }
+
+    #[test]
+    fn test_apply_profile_security_enables_secrets_and_audit() {
+        let config = PreCommitConfig::default();
+        let config = apply_profile(config, Profile::Security);
+        assert!(config.secrets_detection);
+        assert!(config.dependency_audit);
+    }
+
+    #[test]
+    fn test_profile_purpose_strings_are_distinct() {
+        let purposes: Vec<&str> = Profile::ALL.iter().map(Profile::purpose).collect();
+        let mut unique = purposes.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(purposes.len(), unique.len());
+    }
+
+    #[test]
+    fn test_merge_config_file_explicit_path() {
+        let tmp = tempdir().unwrap();
+        let custom_path = tmp.path().join("custom.toml");
+        fs::write(&custom_path, "docker = true\n").unwrap();
+
+        let merged =
+            merge_config_file(PreCommitConfig::default(), tmp.path(), Some(&custom_path)).unwrap();
+        assert!(merged.docker);
+    }
 }
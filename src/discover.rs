@@ -1,17 +1,18 @@
 //! Discovery module for detecting project technologies.
 //!
 //! Scans a repository to detect what technologies are used based on
-//! file extensions, filenames, and file contents.
+//! file extensions, filenames, and folder names.
 
 use crate::config::PreCommitConfig;
 use ignore::WalkBuilder;
 use regex::Regex;
-use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
+use std::process::Command;
 
-// Technology detection indicators
-const PYTHON_INDICATORS: &[&str] = &[
+// Technology detection indicators, split by the kind of directory entry they
+// match against.
+const PYTHON_FILES: &[&str] = &[
     "setup.py",
     "pyproject.toml",
     "requirements.txt",
@@ -20,47 +21,62 @@ const PYTHON_INDICATORS: &[&str] = &[
     "setup.cfg",
     "tox.ini",
     "pytest.ini",
-    ".py",
     "manage.py",
     "__init__.py",
 ];
+const PYTHON_EXTENSIONS: &[&str] = &[".py"];
 
-const JAVASCRIPT_INDICATORS: &[&str] = &[
+const JAVASCRIPT_FILES: &[&str] = &[
     "package.json",
     "yarn.lock",
     "package-lock.json",
     "npm-shrinkwrap.json",
-    ".js",
-    ".mjs",
-    ".cjs",
     "webpack.config.js",
     "vite.config.js",
     "rollup.config.js",
     "babel.config.js",
     ".babelrc",
 ];
+const JAVASCRIPT_EXTENSIONS: &[&str] = &[".js", ".mjs", ".cjs"];
 
-const TYPESCRIPT_INDICATORS: &[&str] = &[
-    "tsconfig.json",
-    "tsconfig.base.json",
-    "tsconfig.build.json",
-    ".ts",
-    ".tsx",
-    ".d.ts",
-];
+const TYPESCRIPT_FILES: &[&str] = &["tsconfig.json", "tsconfig.base.json", "tsconfig.build.json"];
+const TYPESCRIPT_EXTENSIONS: &[&str] = &[".ts", ".tsx", ".d.ts"];
 
-const JSX_INDICATORS: &[&str] = &[
-    ".jsx",
-    ".tsx",
-    "next.config.js",
-    "gatsby-config.js",
-    "react-scripts",
-    ".storybook",
-];
+const JSX_FILES: &[&str] = &["next.config.js", "gatsby-config.js", "react-scripts"];
+const JSX_FOLDERS: &[&str] = &[".storybook"];
+const JSX_EXTENSIONS: &[&str] = &[".jsx", ".tsx"];
+
+const GO_FILES: &[&str] = &["go.mod", "go.sum", "main.go"];
+const GO_FOLDERS: &[&str] = &["vendor"];
+const GO_EXTENSIONS: &[&str] = &[".go"];
+
+const RUST_FILES: &[&str] = &["cargo.toml", "cargo.lock"];
+const RUST_EXTENSIONS: &[&str] = &[".rs"];
+
+const RUBY_FILES: &[&str] = &["gemfile", "gemfile.lock", "rakefile", ".rubocop.yml"];
+const RUBY_EXTENSIONS: &[&str] = &[".rb"];
 
-const GO_INDICATORS: &[&str] = &["go.mod", "go.sum", "main.go", ".go", "vendor"];
+const LUA_FILES: &[&str] = &[".luacheckrc", "stylua.toml"];
+const LUA_EXTENSIONS: &[&str] = &[".lua"];
+
+const DART_FILES: &[&str] = &["pubspec.yaml", "pubspec.lock"];
+const DART_EXTENSIONS: &[&str] = &[".dart"];
+
+const PERL_FILES: &[&str] = &["makefile.pl", "cpanfile", ".perlcriticrc"];
+const PERL_EXTENSIONS: &[&str] = &[".pl", ".pm"];
+
+const SHELL_FILES: &[&str] = &[".shellcheckrc"];
+const SHELL_EXTENSIONS: &[&str] = &[".sh", ".bash"];
+
+const JAVA_FILES: &[&str] = &[
+    "pom.xml",
+    "build.gradle",
+    "build.gradle.kts",
+    "settings.gradle",
+];
+const JAVA_EXTENSIONS: &[&str] = &[".java", ".kt"];
 
-const DOCKER_INDICATORS: &[&str] = &[
+const DOCKER_FILES: &[&str] = &[
     "dockerfile",
     "docker-compose.yml",
     "docker-compose.yaml",
@@ -69,116 +85,373 @@ const DOCKER_INDICATORS: &[&str] = &[
     "dockerfile.prod",
 ];
 
-const YAML_INDICATORS: &[&str] = &[".yml", ".yaml", "docker-compose.yml", "docker-compose.yaml"];
-const JSON_INDICATORS: &[&str] = &[".json"];
-const TOML_INDICATORS: &[&str] = &[".toml", "pyproject.toml"];
-const XML_INDICATORS: &[&str] = &[".xml"];
-
-/// Discover all files in the given path, respecting .gitignore.
-pub fn discover_files(path: &Path) -> HashSet<String> {
-    let mut files = HashSet::new();
-
-    let walker = WalkBuilder::new(path)
-        .hidden(false)
-        .git_ignore(true)
-        .git_global(true)
-        .git_exclude(true)
-        .build();
-
-    for entry in walker.flatten() {
-        if entry.file_type().map(|ft| ft.is_file()).unwrap_or(false) {
-            let file_name = entry.file_name().to_string_lossy().to_lowercase();
-            files.insert(file_name.clone());
-
-            // Also add file extension
-            if let Some(ext) = entry.path().extension() {
-                files.insert(format!(".{}", ext.to_string_lossy().to_lowercase()));
-            }
+const GITHUB_ACTIONS_ROOT_FOLDER: &[&str] = &[".github"];
+const GITHUB_ACTIONS_WORKFLOWS_FOLDER: &[&str] = &["workflows"];
+const GITHUB_ACTIONS_EXTENSIONS: &[&str] = &[".yml", ".yaml"];
+
+const YAML_FILES: &[&str] = &["docker-compose.yml", "docker-compose.yaml"];
+const YAML_EXTENSIONS: &[&str] = &[".yml", ".yaml"];
+const JSON_EXTENSIONS: &[&str] = &[".json"];
+const TOML_FILES: &[&str] = &["pyproject.toml"];
+const TOML_EXTENSIONS: &[&str] = &[".toml"];
+const XML_EXTENSIONS: &[&str] = &[".xml"];
+
+/// Directory-aware scanner, modeled on Starship's `ScanDir`.
+///
+/// Unlike a flat set of lowercased filenames/extensions, this matches each
+/// criterion against its proper entry type: exact filenames, actual
+/// directory names, and file extensions, so a folder name like `vendor`
+/// can't falsely match an unrelated file and vice versa.
+pub struct ScanDir<'a> {
+    path: &'a Path,
+    files: &'a [&'a str],
+    folders: &'a [&'a str],
+    extensions: &'a [&'a str],
+}
+
+impl<'a> ScanDir<'a> {
+    /// Start scanning `path`, matching nothing until criteria are set.
+    pub fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            files: &[],
+            folders: &[],
+            extensions: &[],
         }
     }
 
-    files
-}
+    /// Match entries whose filename (case-insensitive) is one of `files`.
+    pub fn set_files(mut self, files: &'a [&'a str]) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Match entries whose directory name (case-insensitive) is one of `folders`.
+    pub fn set_folders(mut self, folders: &'a [&'a str]) -> Self {
+        self.folders = folders;
+        self
+    }
 
-/// Check if files contain any of the given indicators.
-fn has_indicator(files: &HashSet<String>, indicators: &[&str]) -> bool {
-    indicators
-        .iter()
-        .any(|ind| files.contains(&ind.to_lowercase()))
+    /// Match files whose extension (case-insensitive, with leading dot) is
+    /// one of `extensions`.
+    pub fn set_extensions(mut self, extensions: &'a [&'a str]) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Walk `path` once, respecting `.gitignore`, and report whether any
+    /// entry matches the configured files, folders, or extensions.
+    pub fn is_match(&self) -> bool {
+        if self.files.is_empty() && self.folders.is_empty() && self.extensions.is_empty() {
+            return false;
+        }
+
+        let walker = WalkBuilder::new(self.path)
+            .hidden(false)
+            .git_ignore(true)
+            .git_global(true)
+            .git_exclude(true)
+            .build();
+
+        for entry in walker.flatten() {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            match entry.file_type() {
+                Some(ft) if ft.is_file() => {
+                    if self.files.iter().any(|f| f.eq_ignore_ascii_case(&name)) {
+                        return true;
+                    }
+                    if let Some(ext) = entry.path().extension() {
+                        let ext = format!(".{}", ext.to_string_lossy().to_lowercase());
+                        if self.extensions.iter().any(|e| e.eq_ignore_ascii_case(&ext)) {
+                            return true;
+                        }
+                    }
+                }
+                Some(ft) if ft.is_dir() => {
+                    if self.folders.iter().any(|f| f.eq_ignore_ascii_case(&name)) {
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        false
+    }
 }
 
 /// Detect if this is a Python project.
-pub fn detect_python(files: &HashSet<String>) -> bool {
-    has_indicator(files, PYTHON_INDICATORS)
+pub fn detect_python(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(PYTHON_FILES)
+        .set_extensions(PYTHON_EXTENSIONS)
+        .is_match()
 }
 
 /// Detect if project uses uv with uv.lock file.
-pub fn detect_uv_lock(files: &HashSet<String>) -> bool {
-    files.contains("uv.lock")
+pub fn detect_uv_lock(path: &Path) -> bool {
+    ScanDir::new(path).set_files(&["uv.lock"]).is_match()
 }
 
 /// Detect if this is a JavaScript project.
-pub fn detect_javascript(files: &HashSet<String>) -> bool {
-    has_indicator(files, JAVASCRIPT_INDICATORS)
+pub fn detect_javascript(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(JAVASCRIPT_FILES)
+        .set_extensions(JAVASCRIPT_EXTENSIONS)
+        .is_match()
 }
 
 /// Detect if project uses TypeScript.
-pub fn detect_typescript(files: &HashSet<String>) -> bool {
-    has_indicator(files, TYPESCRIPT_INDICATORS)
+pub fn detect_typescript(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(TYPESCRIPT_FILES)
+        .set_extensions(TYPESCRIPT_EXTENSIONS)
+        .is_match()
 }
 
 /// Detect if project uses JSX/React.
-pub fn detect_jsx(files: &HashSet<String>) -> bool {
-    has_indicator(files, JSX_INDICATORS)
+pub fn detect_jsx(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(JSX_FILES)
+        .set_folders(JSX_FOLDERS)
+        .set_extensions(JSX_EXTENSIONS)
+        .is_match()
+}
+
+/// Parse `package.json` into a generic JSON value, if present and valid.
+fn read_package_json(path: &Path) -> Option<serde_json::Value> {
+    let content = fs::read_to_string(path.join("package.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Determine the JS package manager: the `packageManager` field in
+/// `package.json` (e.g. `"yarn@3.2.1"` -> `"yarn"`) takes priority, falling
+/// back to whichever lockfile is present.
+pub fn detect_package_manager(path: &Path) -> Option<String> {
+    if let Some(name) = read_package_json(path)
+        .as_ref()
+        .and_then(|v| v.get("packageManager"))
+        .and_then(|v| v.as_str())
+        .and_then(|pm| pm.split('@').next())
+    {
+        return Some(name.to_string());
+    }
+
+    if path.join("yarn.lock").exists() {
+        Some("yarn".to_string())
+    } else if path.join("pnpm-lock.yaml").exists() {
+        Some("pnpm".to_string())
+    } else if path.join("package-lock.json").exists() {
+        Some("npm".to_string())
+    } else {
+        None
+    }
+}
+
+/// Read `engines.node` from `package.json`, recorded the same way
+/// `python_version` is.
+pub fn detect_node_version(path: &Path) -> Option<String> {
+    let value = read_package_json(path)?;
+    value.get("engines")?.get("node")?.as_str().map(String::from)
+}
+
+/// Read `package.json`'s `private` flag.
+pub fn detect_js_private(path: &Path) -> bool {
+    read_package_json(path)
+        .and_then(|v| v.get("private").and_then(|p| p.as_bool()))
+        .unwrap_or(false)
 }
 
 /// Detect if this is a Go project.
-pub fn detect_go(files: &HashSet<String>) -> bool {
-    has_indicator(files, GO_INDICATORS)
+pub fn detect_go(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(GO_FILES)
+        .set_folders(GO_FOLDERS)
+        .set_extensions(GO_EXTENSIONS)
+        .is_match()
 }
 
-/// Detect if project uses Docker.
-pub fn detect_docker(files: &HashSet<String>) -> bool {
-    has_indicator(files, DOCKER_INDICATORS)
+/// Detect if this is a Rust project.
+pub fn detect_rust(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(RUST_FILES)
+        .set_extensions(RUST_EXTENSIONS)
+        .is_match()
 }
 
-/// Detect if project uses GitHub Actions.
-pub fn detect_github_actions(path: &Path) -> bool {
-    let workflows_dir = path.join(".github").join("workflows");
-    if workflows_dir.is_dir() {
-        if let Ok(entries) = fs::read_dir(&workflows_dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if let Some(ext) = path.extension() {
-                    let ext_str = ext.to_string_lossy().to_lowercase();
-                    if ext_str == "yml" || ext_str == "yaml" {
-                        return true;
+/// Detect if this is a Ruby project.
+pub fn detect_ruby(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(RUBY_FILES)
+        .set_extensions(RUBY_EXTENSIONS)
+        .is_match()
+}
+
+/// Detect if this is a Lua project.
+pub fn detect_lua(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(LUA_FILES)
+        .set_extensions(LUA_EXTENSIONS)
+        .is_match()
+}
+
+/// Detect if this is a Dart project.
+pub fn detect_dart(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(DART_FILES)
+        .set_extensions(DART_EXTENSIONS)
+        .is_match()
+}
+
+/// Detect if this is a Perl project.
+pub fn detect_perl(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(PERL_FILES)
+        .set_extensions(PERL_EXTENSIONS)
+        .is_match()
+}
+
+/// Detect if this is a shell-scripted project.
+pub fn detect_shell(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(SHELL_FILES)
+        .set_extensions(SHELL_EXTENSIONS)
+        .is_match()
+}
+
+/// Detect if this is a Java/Kotlin (Maven or Gradle) project.
+pub fn detect_java(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(JAVA_FILES)
+        .set_extensions(JAVA_EXTENSIONS)
+        .is_match()
+}
+
+/// Read the project-level `artifactId` and `version` out of a Maven
+/// `pom.xml`, ignoring the same-named elements nested under `<parent>` or
+/// `<dependencies>`/`<dependency>`.
+///
+/// Walks the XML as a stream rather than building a DOM (as Starship's
+/// package module does for `Cargo.toml`/`pom.xml`), tracking element depth so
+/// only `<project>`'s direct children are captured.
+pub fn detect_maven_coordinates(path: &Path) -> (Option<String>, Option<String>) {
+    let content = match fs::read_to_string(path.join("pom.xml")) {
+        Ok(content) => content,
+        Err(_) => return (None, None),
+    };
+
+    let mut reader = quick_xml::Reader::from_str(&content);
+    reader.config_mut().trim_text(true);
+
+    let mut buf = Vec::new();
+    let mut depth: usize = 0;
+    let mut capturing: Option<&'static str> = None;
+    let mut artifact_id = None;
+    let mut version = None;
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(quick_xml::events::Event::Start(e)) => {
+                depth += 1;
+                capturing = if depth == 2 {
+                    match e.local_name().as_ref() {
+                        b"artifactId" => Some("artifactId"),
+                        b"version" => Some("version"),
+                        _ => None,
+                    }
+                } else {
+                    None
+                };
+            }
+            Ok(quick_xml::events::Event::Text(text)) => {
+                if let (Some(field), Ok(value)) = (capturing, text.unescape()) {
+                    match field {
+                        "artifactId" if artifact_id.is_none() => {
+                            artifact_id = Some(value.to_string())
+                        }
+                        "version" if version.is_none() => version = Some(value.to_string()),
+                        _ => {}
                     }
                 }
             }
+            Ok(quick_xml::events::Event::End(_)) => {
+                depth = depth.saturating_sub(1);
+                capturing = None;
+            }
+            Ok(quick_xml::events::Event::Eof) => break,
+            Err(_) => break,
+            _ => {}
         }
+        buf.clear();
     }
-    false
+
+    (artifact_id, version)
+}
+
+/// Detect if project uses Docker.
+pub fn detect_docker(path: &Path) -> bool {
+    ScanDir::new(path).set_files(DOCKER_FILES).is_match()
+}
+
+/// Detect if project uses GitHub Actions: a `.github` folder, a `workflows`
+/// folder, and at least one YAML file. Checked as two separate folder
+/// matches (rather than one match against either name) so a repo with only
+/// one of the two doesn't false-positive.
+pub fn detect_github_actions(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_folders(GITHUB_ACTIONS_ROOT_FOLDER)
+        .is_match()
+        && ScanDir::new(path)
+            .set_folders(GITHUB_ACTIONS_WORKFLOWS_FOLDER)
+            .is_match()
+        && ScanDir::new(path)
+            .set_extensions(GITHUB_ACTIONS_EXTENSIONS)
+            .is_match()
 }
 
 /// Detect YAML files.
-pub fn detect_yaml(files: &HashSet<String>) -> bool {
-    has_indicator(files, YAML_INDICATORS)
+pub fn detect_yaml(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(YAML_FILES)
+        .set_extensions(YAML_EXTENSIONS)
+        .is_match()
 }
 
 /// Detect JSON files.
-pub fn detect_json(files: &HashSet<String>) -> bool {
-    has_indicator(files, JSON_INDICATORS)
+pub fn detect_json(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_extensions(JSON_EXTENSIONS)
+        .is_match()
 }
 
 /// Detect TOML files.
-pub fn detect_toml(files: &HashSet<String>) -> bool {
-    has_indicator(files, TOML_INDICATORS)
+pub fn detect_toml(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_files(TOML_FILES)
+        .set_extensions(TOML_EXTENSIONS)
+        .is_match()
 }
 
 /// Detect XML files.
-pub fn detect_xml(files: &HashSet<String>) -> bool {
-    has_indicator(files, XML_INDICATORS)
+pub fn detect_xml(path: &Path) -> bool {
+    ScanDir::new(path)
+        .set_extensions(XML_EXTENSIONS)
+        .is_match()
+}
+
+/// Read `[package].version` from a crate's Cargo.toml.
+///
+/// Returns `None` for a workspace virtual manifest, which has no `[package]`
+/// table of its own.
+pub fn detect_cargo_version(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path.join("Cargo.toml")).ok()?;
+    let parsed: toml::Table = content.parse().ok()?;
+    parsed
+        .get("package")
+        .and_then(|p| p.as_table())
+        .and_then(|t| t.get("version"))
+        .and_then(|v| v.as_str())
+        .map(String::from)
 }
 
 /// Attempt to detect Python version from project files.
@@ -218,34 +491,152 @@ pub fn detect_python_version(path: &Path) -> Option<String> {
         }
     }
 
+    // Check setup.cfg / setup.py for a `python_requires` constraint
+    for candidate in ["setup.cfg", "setup.py"] {
+        let setup_path = path.join(candidate);
+        if let Ok(content) = fs::read_to_string(&setup_path) {
+            if let Some(version) = extract_python_requires(&content) {
+                return Some(version);
+            }
+        }
+    }
+
     None
 }
 
+/// Pull the first version token out of a `python_requires = "..."` assignment
+/// and normalize it into the `pythonX.Y` form, e.g. `">=3.11"` -> `python3.11`.
+fn extract_python_requires(content: &str) -> Option<String> {
+    let assignment_re = Regex::new(r#"python_requires\s*=\s*['"]?([^'"\n]+)"#).unwrap();
+    let version_re = Regex::new(r"(\d+\.\d+(?:\.\d+)?)").unwrap();
+
+    let assignment = assignment_re.captures(content)?;
+    let caps = version_re.captures(&assignment[1])?;
+    Some(format!("python{}", &caps[1]))
+}
+
+/// Resolve the active Python version, falling through from project files to
+/// the active virtualenv, pyenv, and finally the interpreter itself.
+///
+/// Each subprocess-based source is guarded so a missing binary (no pyenv, no
+/// `python_binary` on PATH) just falls through to the next source instead of
+/// failing detection outright.
+pub fn detect_python_version_resolved(path: &Path, python_binary: &str) -> Option<String> {
+    detect_python_version(path)
+        .or_else(|| detect_python_version_from_virtual_env())
+        .or_else(|| {
+            if path.join(".python-version").exists() {
+                detect_python_version_from_pyenv()
+            } else {
+                None
+            }
+        })
+        .or_else(|| detect_python_version_from_binary(python_binary))
+}
+
+/// Read `$VIRTUAL_ENV/pyvenv.cfg` for a `version = 3.x.y` line.
+fn detect_python_version_from_virtual_env() -> Option<String> {
+    let virtual_env = std::env::var("VIRTUAL_ENV").ok()?;
+    detect_python_version_from_venv_path(Path::new(&virtual_env))
+}
+
+/// Read `<venv_path>/pyvenv.cfg` for a `version = 3.x.y` line. Split out from
+/// `detect_python_version_from_virtual_env` so tests can exercise the parsing
+/// logic directly with a path, instead of mutating the process-global
+/// `VIRTUAL_ENV` (which would race other tests running in parallel).
+fn detect_python_version_from_venv_path(venv_path: &Path) -> Option<String> {
+    let content = fs::read_to_string(venv_path.join("pyvenv.cfg")).ok()?;
+    let re = Regex::new(r"(?m)^version\s*=\s*(\d+\.\d+(?:\.\d+)?)").unwrap();
+    let caps = re.captures(&content)?;
+    Some(format!("python{}", &caps[1]))
+}
+
+/// Shell out to `pyenv version-name` to resolve a `.python-version` pin.
+fn detect_python_version_from_pyenv() -> Option<String> {
+    let output = Command::new("pyenv").arg("version-name").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let version = String::from_utf8_lossy(&output.stdout);
+    let re = Regex::new(r"(\d+\.\d+(?:\.\d+)?)").unwrap();
+    let caps = re.captures(&version)?;
+    Some(format!("python{}", &caps[1]))
+}
+
+/// Run `<python_binary> --version` and parse its `Python 3.x.y` output.
+fn detect_python_version_from_binary(python_binary: &str) -> Option<String> {
+    let output = Command::new(python_binary).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // Python 2 prints its version to stderr; Python 3 prints it to stdout.
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let re = Regex::new(r"Python\s+(\d+\.\d+(?:\.\d+)?)").unwrap();
+    let caps = re.captures(&combined)?;
+    Some(format!("python{}", &caps[1]))
+}
+
 /// Discover project configuration by analyzing files.
 pub fn discover_config(path: &Path) -> PreCommitConfig {
-    let files = discover_files(path);
-
-    let has_python = detect_python(&files);
-    let has_js = detect_javascript(&files);
-    let has_typescript = detect_typescript(&files);
-    let has_jsx = detect_jsx(&files);
-    let has_go = detect_go(&files);
-    let has_docker = detect_docker(&files);
+    let has_python = detect_python(path);
+    let has_js = detect_javascript(path);
+    let has_typescript = detect_typescript(path);
+    let has_jsx = detect_jsx(path);
+    let has_go = detect_go(path);
+    let has_rust = detect_rust(path);
+    let has_java = detect_java(path);
+    let has_ruby = detect_ruby(path);
+    let has_lua = detect_lua(path);
+    let has_dart = detect_dart(path);
+    let has_perl = detect_perl(path);
+    let has_shell = detect_shell(path);
+    let has_docker = detect_docker(path);
     let has_github_actions = detect_github_actions(path);
 
-    let has_yaml = detect_yaml(&files);
-    let has_json = detect_json(&files);
-    let has_toml = detect_toml(&files);
-    let has_xml = detect_xml(&files);
+    let has_yaml = detect_yaml(path);
+    let has_json = detect_json(path);
+    let has_toml = detect_toml(path);
+    let has_xml = detect_xml(path);
 
+    let python_binary = "python3".to_string();
     let python_version = if has_python {
-        detect_python_version(path)
+        detect_python_version_resolved(path, &python_binary)
     } else {
         None
     };
 
+    let cargo_version = if has_rust {
+        detect_cargo_version(path)
+    } else {
+        None
+    };
+
+    let (package_manager, node_version, js_private) = if has_js {
+        (
+            detect_package_manager(path),
+            detect_node_version(path),
+            detect_js_private(path),
+        )
+    } else {
+        (None, None, false)
+    };
+
+    let (maven_artifact_id, maven_version) = if has_java {
+        detect_maven_coordinates(path)
+    } else {
+        (None, None)
+    };
+
     PreCommitConfig {
         python_version,
+        python_binary,
+        cargo_version,
         yaml_check: has_yaml,
         json_check: has_json,
         toml_check: has_toml,
@@ -255,7 +646,7 @@ pub fn discover_config(path: &Path) -> PreCommitConfig {
         symlinks: false,
         python_base: has_python,
         python: has_python,
-        uv_lock: detect_uv_lock(&files),
+        uv_lock: detect_uv_lock(path),
         pyrefly_args: None,
         docker: has_docker,
         dockerfile_linting: true,
@@ -268,59 +659,304 @@ pub fn discover_config(path: &Path) -> PreCommitConfig {
         jsx: has_jsx,
         prettier_config: None,
         eslint_config: None,
+        package_manager,
+        node_version,
+        js_private,
         go: has_go,
         go_critic: false,
+        maven_artifact_id,
+        maven_version,
+        java: has_java,
+        java_format: true,
+        checkstyle: false,
+        rust: has_rust,
+        rustfmt: true,
+        clippy: has_rust,
+        cargo_check: has_rust,
+        ruby: has_ruby,
+        lua: has_lua,
+        dart: has_dart,
+        perl: has_perl,
+        shell: has_shell,
+        ..Default::default()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashSet;
     use tempfile::tempdir;
 
     #[test]
     fn test_detect_python() {
-        let mut files = HashSet::new();
-        files.insert("pyproject.toml".to_string());
-        assert!(detect_python(&files));
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("pyproject.toml"), "").unwrap();
+        assert!(detect_python(tmp.path()));
     }
 
     #[test]
     fn test_detect_python_by_extension() {
-        let mut files = HashSet::new();
-        files.insert(".py".to_string());
-        assert!(detect_python(&files));
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("app.py"), "").unwrap();
+        assert!(detect_python(tmp.path()));
     }
 
     #[test]
     fn test_detect_javascript() {
-        let mut files = HashSet::new();
-        files.insert("package.json".to_string());
-        assert!(detect_javascript(&files));
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("package.json"), "").unwrap();
+        assert!(detect_javascript(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_package_manager_from_package_manager_field() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"packageManager": "yarn@3.2.1"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            detect_package_manager(tmp.path()),
+            Some("yarn".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_package_manager_from_lockfile() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("package.json"), "{}").unwrap();
+        fs::write(tmp.path().join("pnpm-lock.yaml"), "").unwrap();
+        assert_eq!(
+            detect_package_manager(tmp.path()),
+            Some("pnpm".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_package_manager_prefers_field_over_lockfile() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"packageManager": "pnpm@8.0.0"}"#,
+        )
+        .unwrap();
+        fs::write(tmp.path().join("package-lock.json"), "").unwrap();
+        assert_eq!(
+            detect_package_manager(tmp.path()),
+            Some("pnpm".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_package_manager_none_without_signal() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("package.json"), "{}").unwrap();
+        assert_eq!(detect_package_manager(tmp.path()), None);
+    }
+
+    #[test]
+    fn test_detect_node_version() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"engines": {"node": ">=18.0.0"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            detect_node_version(tmp.path()),
+            Some(">=18.0.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_node_version_missing() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("package.json"), "{}").unwrap();
+        assert_eq!(detect_node_version(tmp.path()), None);
+    }
+
+    #[test]
+    fn test_detect_js_private() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("package.json"), r#"{"private": true}"#).unwrap();
+        assert!(detect_js_private(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_js_private_defaults_to_false() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("package.json"), "{}").unwrap();
+        assert!(!detect_js_private(tmp.path()));
     }
 
     #[test]
     fn test_detect_go() {
-        let mut files = HashSet::new();
-        files.insert("go.mod".to_string());
-        assert!(detect_go(&files));
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("go.mod"), "").unwrap();
+        assert!(detect_go(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_go_by_vendor_folder() {
+        let tmp = tempdir().unwrap();
+        fs::create_dir(tmp.path().join("vendor")).unwrap();
+        assert!(detect_go(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_go_does_not_match_file_named_vendor() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("vendor"), "not a folder").unwrap();
+        assert!(!detect_go(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_jsx_by_storybook_folder() {
+        let tmp = tempdir().unwrap();
+        fs::create_dir(tmp.path().join(".storybook")).unwrap();
+        assert!(detect_jsx(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_rust() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("Cargo.toml"), "").unwrap();
+        assert!(detect_rust(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_ruby() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("Gemfile"), "").unwrap();
+        assert!(detect_ruby(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_lua() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("stylua.toml"), "").unwrap();
+        assert!(detect_lua(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_dart() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("pubspec.yaml"), "").unwrap();
+        assert!(detect_dart(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_perl() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("cpanfile"), "").unwrap();
+        assert!(detect_perl(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_shell() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join(".shellcheckrc"), "").unwrap();
+        assert!(detect_shell(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_java_by_pom() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("pom.xml"), "").unwrap();
+        assert!(detect_java(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_java_by_gradle_kts() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("build.gradle.kts"), "").unwrap();
+        assert!(detect_java(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_maven_coordinates_ignores_nested_versions() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("pom.xml"),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<project>
+    <parent>
+        <groupId>org.springframework.boot</groupId>
+        <artifactId>spring-boot-starter-parent</artifactId>
+        <version>3.2.0</version>
+    </parent>
+    <artifactId>my-app</artifactId>
+    <version>1.4.2</version>
+    <dependencies>
+        <dependency>
+            <groupId>org.example</groupId>
+            <artifactId>some-lib</artifactId>
+            <version>9.9.9</version>
+        </dependency>
+    </dependencies>
+</project>
+"#,
+        )
+        .unwrap();
+
+        let (artifact_id, version) = detect_maven_coordinates(tmp.path());
+        assert_eq!(artifact_id, Some("my-app".to_string()));
+        assert_eq!(version, Some("1.4.2".to_string()));
+    }
+
+    #[test]
+    fn test_detect_maven_coordinates_missing_pom_returns_none() {
+        let tmp = tempdir().unwrap();
+        let (artifact_id, version) = detect_maven_coordinates(tmp.path());
+        assert_eq!(artifact_id, None);
+        assert_eq!(version, None);
     }
 
     #[test]
     fn test_detect_docker() {
-        let mut files = HashSet::new();
-        files.insert("dockerfile".to_string());
-        assert!(detect_docker(&files));
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join("Dockerfile"), "").unwrap();
+        assert!(detect_docker(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_github_actions() {
+        let tmp = tempdir().unwrap();
+        let workflows = tmp.path().join(".github").join("workflows");
+        fs::create_dir_all(&workflows).unwrap();
+        fs::write(workflows.join("ci.yml"), "").unwrap();
+        assert!(detect_github_actions(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_github_actions_requires_both_folders() {
+        let tmp = tempdir().unwrap();
+        // Unrelated top-level `workflows/` dir, no `.github` folder at all.
+        let workflows = tmp.path().join("workflows");
+        fs::create_dir_all(&workflows).unwrap();
+        fs::write(workflows.join("ci.yml"), "").unwrap();
+        assert!(!detect_github_actions(tmp.path()));
+    }
+
+    #[test]
+    fn test_detect_github_actions_does_not_match_github_without_workflows() {
+        let tmp = tempdir().unwrap();
+        let github = tmp.path().join(".github");
+        fs::create_dir_all(&github).unwrap();
+        fs::write(github.join("dependabot.yml"), "").unwrap();
+        assert!(!detect_github_actions(tmp.path()));
     }
 
     #[test]
     fn test_no_false_positives() {
-        let files = HashSet::new();
-        assert!(!detect_python(&files));
-        assert!(!detect_javascript(&files));
-        assert!(!detect_go(&files));
-        assert!(!detect_docker(&files));
+        let tmp = tempdir().unwrap();
+        assert!(!detect_python(tmp.path()));
+        assert!(!detect_javascript(tmp.path()));
+        assert!(!detect_go(tmp.path()));
+        assert!(!detect_rust(tmp.path()));
+        assert!(!detect_docker(tmp.path()));
+        assert!(!detect_github_actions(tmp.path()));
     }
 
     #[test]
@@ -363,4 +999,102 @@ mod tests {
             Some("python3.12.1".to_string())
         );
     }
+
+    #[test]
+    fn test_detect_python_version_from_setup_cfg() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("setup.cfg"),
+            "[options]\npython_requires = >=3.9\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            detect_python_version(tmp.path()),
+            Some("python3.9".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_python_version_from_setup_py() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("setup.py"),
+            "setup(\n    python_requires='>=3.8,<4',\n)\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            detect_python_version(tmp.path()),
+            Some("python3.8".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_python_version_none_when_no_signal() {
+        let tmp = tempdir().unwrap();
+        assert_eq!(detect_python_version(tmp.path()), None);
+    }
+
+    #[test]
+    fn test_resolved_prefers_file_signal_over_binary() {
+        let tmp = tempdir().unwrap();
+        fs::write(tmp.path().join(".python-version"), "3.12.1\n").unwrap();
+        assert_eq!(
+            detect_python_version_resolved(tmp.path(), "python3"),
+            Some("python3.12.1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_python_version_from_venv_path() {
+        let venv = tempdir().unwrap();
+        fs::write(venv.path().join("pyvenv.cfg"), "version = 3.9.7\n").unwrap();
+        assert_eq!(
+            detect_python_version_from_venv_path(venv.path()),
+            Some("python3.9.7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_python_version_from_venv_path_missing_cfg() {
+        let venv = tempdir().unwrap();
+        assert_eq!(detect_python_version_from_venv_path(venv.path()), None);
+    }
+
+    #[test]
+    fn test_detect_cargo_version() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[package]\nname = \"prec-templ\"\nversion = \"1.2.3\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            detect_cargo_version(tmp.path()),
+            Some("1.2.3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_cargo_version_workspace_manifest_returns_none() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+
+        assert_eq!(detect_cargo_version(tmp.path()), None);
+    }
+
+    #[test]
+    fn test_resolved_falls_back_to_binary_when_missing_binary_is_safe() {
+        let tmp = tempdir().unwrap();
+        assert_eq!(
+            detect_python_version_resolved(tmp.path(), "definitely-not-a-real-binary"),
+            None
+        );
+    }
 }
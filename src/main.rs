@@ -5,11 +5,16 @@
 mod cli;
 mod config;
 mod discover;
+mod merge;
+mod overrides;
 mod render;
+mod revisions;
+mod schema;
 mod ui;
 
-use cli::Cli;
+use cli::{Cli, Commands};
 use console::style;
+use regex::Regex;
 use std::fs;
 use std::path::Path;
 use std::process::{Command, Output};
@@ -21,17 +26,76 @@ fn main() {
     }
 }
 
+/// Whether to overwrite `.pre-commit-config.yaml` or only check it for drift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Overwrite,
+    Verify,
+}
+
 fn run() -> Result<(), String> {
     let args = Cli::parse_args();
+
+    if let Some(Commands::Schema) = args.command {
+        println!("{}", schema::document()?);
+        return Ok(());
+    }
+
     let path = resolve_directory_path(&args.path)?;
+    let config_path = args
+        .config
+        .as_deref()
+        .map(resolve_directory_path_parent)
+        .transpose()?;
+    let mode = if args.check {
+        Mode::Verify
+    } else {
+        Mode::Overwrite
+    };
 
     if args.interactive {
-        run_interactive(&path)
+        run_interactive(&path, config_path.as_deref(), args.autoupdate, args.profile)
     } else {
-        run_auto(&path, args.generate_only)
+        run_auto(
+            &path,
+            args.generate_only,
+            config_path.as_deref(),
+            args.autoupdate,
+            mode,
+            args.profile,
+            args.changed_only,
+            args.since.as_deref(),
+            args.force,
+        )
     }
 }
 
+/// Resolve the hook repo revisions to render with, refreshing them over the
+/// network first when `autoupdate` is requested.
+fn resolve_revisions(autoupdate: bool) -> std::collections::HashMap<String, String> {
+    let pinned = revisions::default_revisions();
+    if !autoupdate {
+        return pinned;
+    }
+
+    let (updated, changes) = revisions::autoupdate(&pinned);
+    if changes.is_empty() {
+        ui::print_info("All hook revisions are already up to date.");
+    } else {
+        ui::print_info("Updated hook revisions:");
+        for (repo, old_rev, new_rev) in changes {
+            println!("  {}: {} -> {}", repo, old_rev, new_rev);
+        }
+    }
+    updated
+}
+
+/// Resolve a `--config` override path against the current working directory.
+fn resolve_directory_path_parent(path: &Path) -> Result<std::path::PathBuf, String> {
+    path.canonicalize()
+        .map_err(|e| format!("Invalid --config path: {}", e))
+}
+
 fn resolve_directory_path(path: &Path) -> Result<std::path::PathBuf, String> {
     let resolved = path
         .canonicalize()
@@ -43,7 +107,12 @@ fn resolve_directory_path(path: &Path) -> Result<std::path::PathBuf, String> {
 }
 
 /// Run in interactive mode - display UI, collect preferences, output to stdout.
-fn run_interactive(path: &Path) -> Result<(), String> {
+fn run_interactive(
+    path: &Path,
+    config_path: Option<&Path>,
+    autoupdate: bool,
+    profile: Option<config::Profile>,
+) -> Result<(), String> {
     println!();
     println!(
         "{}",
@@ -76,13 +145,21 @@ fn run_interactive(path: &Path) -> Result<(), String> {
     println!("Analyzing project at: {}", style(path.display()).cyan());
     println!();
 
+    let profile = profile.unwrap_or_else(|| ui::select_profile());
+
     // Detect technologies with spinner
     let spinner = ui::create_spinner("Detecting technologies...");
-    let detected_config = discover::discover_config(path);
+    let mut detected_config = discover::discover_config(path);
+    detected_config = config::merge_config_file(detected_config, path, config_path)?;
+    let template_overrides = overrides::load(path)?;
+    detected_config = config::apply_profile(detected_config, profile);
     spinner.finish_and_clear();
 
-    // Display detected technologies
+    // Display detected technologies, noting anything from the override file
     ui::display_detected_technologies(&detected_config);
+    if let Some(ref template_overrides) = template_overrides {
+        ui::display_overrides(template_overrides);
+    }
 
     // Ask if user wants to customize
     let customize = dialoguer::Confirm::new()
@@ -100,8 +177,20 @@ fn run_interactive(path: &Path) -> Result<(), String> {
     println!();
 
     // Generate configuration with spinner
+    let mut revisions = resolve_revisions(autoupdate);
+    let extra_repos = if let Some(ref template_overrides) = template_overrides {
+        let (pins, extras) = overrides::split_repo_overrides(template_overrides, &revisions);
+        revisions.extend(pins);
+        extras
+    } else {
+        Vec::new()
+    };
     let spinner = ui::create_spinner("Generating pre-commit configuration...");
-    let yaml = render::render_config(&final_config)?;
+    let mut yaml =
+        render::render_config_with_revisions_and_extras(&final_config, &revisions, &extra_repos)?;
+    if let Some(ref template_overrides) = template_overrides {
+        yaml = overrides::apply_hook_filters(&yaml, template_overrides)?;
+    }
     spinner.finish_and_clear();
 
     // Output to stdout in interactive mode
@@ -111,24 +200,81 @@ fn run_interactive(path: &Path) -> Result<(), String> {
 }
 
 /// Run in auto-generate mode - detect, generate, save, and run pre-commit.
-fn run_auto(path: &Path, generate_only: bool) -> Result<(), String> {
-    run_auto_with_command(path, generate_only, "pre-commit")
+fn run_auto(
+    path: &Path,
+    generate_only: bool,
+    config_path: Option<&Path>,
+    autoupdate: bool,
+    mode: Mode,
+    profile: Option<config::Profile>,
+    changed_only: bool,
+    since: Option<&str>,
+    force: bool,
+) -> Result<(), String> {
+    run_auto_with_command(
+        path,
+        generate_only,
+        "pre-commit",
+        config_path,
+        autoupdate,
+        mode,
+        profile,
+        changed_only,
+        since,
+        force,
+    )
 }
 
 fn run_auto_with_command(
     path: &Path,
     generate_only: bool,
     pre_commit_cmd: &str,
+    config_path: Option<&Path>,
+    autoupdate: bool,
+    mode: Mode,
+    profile: Option<config::Profile>,
+    changed_only: bool,
+    since: Option<&str>,
+    force: bool,
 ) -> Result<(), String> {
     // Detect technologies
-    let config = discover::discover_config(path);
+    let mut config = discover::discover_config(path);
+    config = config::merge_config_file(config, path, config_path)?;
+    let template_overrides = overrides::load(path)?;
+    if let Some(profile) = profile {
+        config = config::apply_profile(config, profile);
+    }
 
     // Generate YAML
-    let yaml = render::render_config(&config)?;
+    let mut revisions = resolve_revisions(autoupdate);
+    let extra_repos = if let Some(ref template_overrides) = template_overrides {
+        let (pins, extras) = overrides::split_repo_overrides(template_overrides, &revisions);
+        revisions.extend(pins);
+        extras
+    } else {
+        Vec::new()
+    };
+    let mut yaml = render::render_config_with_revisions_and_extras(&config, &revisions, &extra_repos)?;
+    if let Some(ref template_overrides) = template_overrides {
+        yaml = overrides::apply_hook_filters(&yaml, template_overrides)?;
+    }
 
-    // Save configuration
+    if mode == Mode::Verify {
+        return verify_config(path, &yaml);
+    }
+
+    // Save configuration, merging into an existing file unless --force
     let config_file = path.join(".pre-commit-config.yaml");
-    fs::write(&config_file, &yaml).map_err(|e| format!("Failed to write config: {}", e))?;
+    let yaml_to_write = if !force && config_file.exists() {
+        let existing_yaml = fs::read_to_string(&config_file)
+            .map_err(|e| format!("Failed to read existing config: {}", e))?;
+        let (merged_yaml, summary) = merge::merge(&existing_yaml, &yaml)?;
+        ui::display_merge_summary(&summary);
+        merged_yaml
+    } else {
+        yaml
+    };
+    fs::write(&config_file, &yaml_to_write).map_err(|e| format!("Failed to write config: {}", e))?;
     ui::print_success(&format!(
         "Configuration saved to {}",
         style(config_file.display()).green()
@@ -159,8 +305,22 @@ fn run_auto_with_command(
         }
     }
 
-    // Run pre-commit on all files
-    match run_command(pre_commit_cmd, path, &["run", "--all-files"]) {
+    // Run pre-commit, scoped to changed files when requested
+    let run_args: Vec<String> = if changed_only {
+        let changed_files = collect_changed_files(path, since)?;
+        if changed_files.is_empty() {
+            ui::print_info("No changed files to check.");
+            return Ok(());
+        }
+        let mut args = vec!["run".to_string(), "--files".to_string()];
+        args.extend(changed_files);
+        args
+    } else {
+        vec!["run".to_string(), "--all-files".to_string()]
+    };
+    let run_args: Vec<&str> = run_args.iter().map(String::as_str).collect();
+
+    match run_command(pre_commit_cmd, path, &run_args) {
         Ok(output) => {
             if output.status.success() {
                 ui::print_success("Pre-commit setup complete and all hooks passed!");
@@ -177,10 +337,102 @@ fn run_auto_with_command(
     Ok(())
 }
 
+/// Compare the generated config against the on-disk `.pre-commit-config.yaml`
+/// and report drift as an error with a unified diff, instead of writing.
+fn verify_config(path: &Path, generated_yaml: &str) -> Result<(), String> {
+    let config_file = path.join(".pre-commit-config.yaml");
+    let existing_yaml = fs::read_to_string(&config_file).unwrap_or_default();
+
+    if normalize_yaml(&existing_yaml) == normalize_yaml(generated_yaml) {
+        ui::print_success("Configuration is up to date.");
+        return Ok(());
+    }
+
+    let diff = similar::TextDiff::from_lines(&existing_yaml, generated_yaml)
+        .unified_diff()
+        .header(
+            &format!("{} (committed)", config_file.display()),
+            &format!("{} (generated)", config_file.display()),
+        )
+        .to_string();
+
+    Err(format!(
+        "Configuration drift detected in {}:\n{}",
+        config_file.display(),
+        diff
+    ))
+}
+
+/// Normalize YAML for comparison by trimming trailing whitespace per line and
+/// dropping any line embedding `render_config`'s generated-at timestamp, so
+/// formatting noise and the fresh `Utc::now()` baked into every render don't
+/// register as drift.
+fn normalize_yaml(yaml: &str) -> String {
+    let timestamp_re = Regex::new(r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z").unwrap();
+    yaml.lines()
+        .map(str::trim_end)
+        .filter(|line| !timestamp_re.is_match(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn run_command(command: &str, path: &Path, args: &[&str]) -> Result<Output, std::io::Error> {
     Command::new(command).args(args).current_dir(path).output()
 }
 
+/// Collect paths changed since `since` (or `HEAD` when not given) via
+/// `git diff --name-only` plus untracked files from `git ls-files --others`
+/// (so brand-new files aren't silently skipped on a project's first run),
+/// pruned to paths that still exist on disk (so deleted files aren't handed
+/// to `pre-commit run --files`).
+fn collect_changed_files(path: &Path, since: Option<&str>) -> Result<Vec<String>, String> {
+    let diff_base = since.unwrap_or("HEAD");
+    let diff_output = Command::new("git")
+        .args(["diff", "--name-only", diff_base])
+        .current_dir(path)
+        .output()
+        .map_err(|e| format!("Failed to run git diff: {}", e))?;
+
+    if !diff_output.status.success() {
+        return Err(format!(
+            "git diff against {} failed: {}",
+            diff_base,
+            String::from_utf8_lossy(&diff_output.stderr).trim()
+        ));
+    }
+
+    let untracked_output = Command::new("git")
+        .args(["ls-files", "--others", "--exclude-standard"])
+        .current_dir(path)
+        .output()
+        .map_err(|e| format!("Failed to run git ls-files: {}", e))?;
+
+    if !untracked_output.status.success() {
+        return Err(format!(
+            "git ls-files failed: {}",
+            String::from_utf8_lossy(&untracked_output.stderr).trim()
+        ));
+    }
+
+    let diff_files = String::from_utf8_lossy(&diff_output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    let untracked_files = String::from_utf8_lossy(&untracked_output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+    let mut changed: Vec<String> = diff_files
+        .into_iter()
+        .chain(untracked_files)
+        .filter(|f| path.join(f).exists())
+        .collect();
+    changed.sort();
+    changed.dedup();
+    Ok(changed)
+}
+
 fn format_command_output(output: &Output) -> String {
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -244,7 +496,7 @@ exit 0
         let tmp = tempdir().unwrap();
         let (cmd, calls_log) = create_fake_pre_commit(tmp.path(), false);
 
-        let result = run_auto_with_command(tmp.path(), true, &cmd);
+        let result = run_auto_with_command(tmp.path(), true, &cmd, None, false, Mode::Overwrite, None, false, None, true);
         assert!(result.is_ok());
         assert!(tmp.path().join(".pre-commit-config.yaml").exists());
         assert!(!calls_log.exists());
@@ -256,7 +508,7 @@ exit 0
         let tmp = tempdir().unwrap();
         let (cmd, calls_log) = create_fake_pre_commit(tmp.path(), true);
 
-        let result = run_auto_with_command(tmp.path(), false, &cmd);
+        let result = run_auto_with_command(tmp.path(), false, &cmd, None, false, Mode::Overwrite, None, false, None, true);
         assert!(result.is_ok());
 
         let calls = fs::read_to_string(calls_log).unwrap();
@@ -270,7 +522,7 @@ exit 0
         let tmp = tempdir().unwrap();
         let (cmd, calls_log) = create_fake_pre_commit(tmp.path(), false);
 
-        let result = run_auto_with_command(tmp.path(), false, &cmd);
+        let result = run_auto_with_command(tmp.path(), false, &cmd, None, false, Mode::Overwrite, None, false, None, true);
         assert!(result.is_ok());
 
         let calls = fs::read_to_string(calls_log).unwrap();
@@ -278,6 +530,333 @@ exit 0
         assert_eq!(lines, vec!["install", "run --all-files"]);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_run_auto_changed_only_runs_with_files_arg() {
+        let tmp = tempdir().unwrap();
+        init_git_repo(tmp.path());
+        // Keep the generated config out of the untracked-file list
+        fs::write(tmp.path().join(".gitignore"), ".pre-commit-config.yaml\n").unwrap();
+        fs::write(tmp.path().join("a.txt"), "one\n").unwrap();
+        commit_all(tmp.path(), "initial");
+        fs::write(tmp.path().join("a.txt"), "two\n").unwrap();
+
+        // The fake pre-commit and its call log live outside the repo so they
+        // aren't collected as untracked changes
+        let tools = tempdir().unwrap();
+        let (cmd, calls_log) = create_fake_pre_commit(tools.path(), false);
+
+        let result = run_auto_with_command(
+            tmp.path(),
+            false,
+            &cmd,
+            None,
+            false,
+            Mode::Overwrite,
+            None,
+            true,
+            None,
+            true,
+        );
+        assert!(result.is_ok());
+
+        let calls = fs::read_to_string(calls_log).unwrap();
+        let lines: Vec<_> = calls.lines().collect();
+        assert_eq!(lines, vec!["install", "run --files a.txt"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_auto_changed_only_skips_run_when_nothing_changed() {
+        let tmp = tempdir().unwrap();
+        init_git_repo(tmp.path());
+        // Keep the generated config out of the untracked-file list
+        fs::write(tmp.path().join(".gitignore"), ".pre-commit-config.yaml\n").unwrap();
+        fs::write(tmp.path().join("a.txt"), "one\n").unwrap();
+        commit_all(tmp.path(), "initial");
+
+        // The fake pre-commit and its call log live outside the repo so they
+        // aren't collected as untracked changes
+        let tools = tempdir().unwrap();
+        let (cmd, calls_log) = create_fake_pre_commit(tools.path(), false);
+
+        let result = run_auto_with_command(
+            tmp.path(),
+            false,
+            &cmd,
+            None,
+            false,
+            Mode::Overwrite,
+            None,
+            true,
+            None,
+            true,
+        );
+        assert!(result.is_ok());
+
+        let calls = fs::read_to_string(calls_log).unwrap();
+        assert_eq!(calls.trim(), "install");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_auto_verify_mode_does_not_write_or_run_commands() {
+        let tmp = tempdir().unwrap();
+        let (cmd, calls_log) = create_fake_pre_commit(tmp.path(), false);
+
+        let result = run_auto_with_command(tmp.path(), false, &cmd, None, false, Mode::Verify, None, false, None, true);
+        assert!(result.is_err());
+        assert!(!tmp.path().join(".pre-commit-config.yaml").exists());
+        assert!(!calls_log.exists());
+    }
+
+    #[test]
+    fn test_run_auto_verify_mode_passes_when_config_matches() {
+        let tmp = tempdir().unwrap();
+        // Detection counts the committed config as a YAML file, so render the
+        // expected config with that file already in place
+        fs::write(tmp.path().join(".pre-commit-config.yaml"), "").unwrap();
+        let config = discover::discover_config(tmp.path());
+        let revisions = resolve_revisions(false);
+        let yaml = render::render_config_with_revisions(&config, &revisions).unwrap();
+        fs::write(tmp.path().join(".pre-commit-config.yaml"), &yaml).unwrap();
+
+        let result = run_auto_with_command(
+            tmp.path(),
+            false,
+            "pre-commit",
+            None,
+            false,
+            Mode::Verify,
+            None,
+            false,
+            None,
+            true,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_run_auto_verify_mode_fails_on_missing_config() {
+        let tmp = tempdir().unwrap();
+
+        let result = run_auto_with_command(
+            tmp.path(),
+            false,
+            "pre-commit",
+            None,
+            false,
+            Mode::Verify,
+            None,
+            false,
+            None,
+            true,
+        );
+        let err = result.unwrap_err();
+        assert!(err.contains("Configuration drift detected"));
+    }
+
+    #[test]
+    fn test_normalize_yaml_ignores_trailing_whitespace() {
+        assert_eq!(normalize_yaml("a: 1  \nb: 2\n"), normalize_yaml("a: 1\nb: 2"));
+    }
+
+    #[test]
+    fn test_normalize_yaml_ignores_generated_timestamp() {
+        let a = "# Generated 2026-07-29T12:00:00Z\na: 1\n";
+        let b = "# Generated 2026-07-29T12:00:07Z\na: 1\n";
+        assert_eq!(normalize_yaml(a), normalize_yaml(b));
+    }
+
+    #[test]
+    fn test_run_auto_force_adds_repo_from_override_file() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join(".pre-commit-template.toml"),
+            r#"
+[[repos]]
+name = "custom-linter"
+url = "https://github.com/example/custom-linter"
+rev = "v1.0.0"
+"#,
+        )
+        .unwrap();
+
+        let result = run_auto_with_command(tmp.path(), true, "pre-commit", None, false, Mode::Overwrite, None, false, None, true);
+        assert!(result.is_ok());
+
+        let yaml = fs::read_to_string(tmp.path().join(".pre-commit-config.yaml")).unwrap();
+        assert!(yaml.contains("https://github.com/example/custom-linter"));
+        assert!(yaml.contains("custom-linter"));
+        assert!(yaml.contains("v1.0.0"));
+    }
+
+    #[test]
+    fn test_run_auto_excludes_hook_by_rendered_id_from_override_file() {
+        // case_conflict/executables are always detected on, regardless of
+        // directory contents, so their hooks are a stable exclusion target.
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join(".pre-commit-template.toml"),
+            "excluded = [\"check-case-conflict\"]\n",
+        )
+        .unwrap();
+
+        let result = run_auto_with_command(tmp.path(), true, "pre-commit", None, false, Mode::Overwrite, None, false, None, true);
+        assert!(result.is_ok());
+
+        let yaml = fs::read_to_string(tmp.path().join(".pre-commit-config.yaml")).unwrap();
+        assert!(!yaml.contains("check-case-conflict"));
+    }
+
+    #[test]
+    fn test_run_auto_accepts_a_profile() {
+        let tmp = tempdir().unwrap();
+
+        let result = run_auto_with_command(
+            tmp.path(),
+            true,
+            "pre-commit",
+            None,
+            false,
+            Mode::Overwrite,
+            Some(config::Profile::Security),
+            false,
+            None,
+            true,
+        );
+        assert!(result.is_ok());
+        assert!(tmp.path().join(".pre-commit-config.yaml").exists());
+    }
+
+    #[test]
+    fn test_run_auto_merges_into_existing_config_without_force() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join(".pre-commit-config.yaml"),
+            "repos:\n  - repo: https://github.com/example/hand-added\n    rev: v1.0.0\n    hooks:\n      - id: hand-added-hook\n",
+        )
+        .unwrap();
+
+        let result = run_auto_with_command(
+            tmp.path(),
+            true,
+            "pre-commit",
+            None,
+            false,
+            Mode::Overwrite,
+            None,
+            false,
+            None,
+            false,
+        );
+        assert!(result.is_ok());
+
+        let yaml = fs::read_to_string(tmp.path().join(".pre-commit-config.yaml")).unwrap();
+        assert!(yaml.contains("hand-added-hook"));
+        assert!(yaml.contains("https://github.com/pre-commit/pre-commit-hooks"));
+    }
+
+    #[test]
+    fn test_run_auto_force_overwrites_existing_config() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join(".pre-commit-config.yaml"),
+            "repos:\n  - repo: https://github.com/example/hand-added\n    rev: v1.0.0\n    hooks:\n      - id: hand-added-hook\n",
+        )
+        .unwrap();
+
+        let result = run_auto_with_command(
+            tmp.path(),
+            true,
+            "pre-commit",
+            None,
+            false,
+            Mode::Overwrite,
+            None,
+            false,
+            None,
+            true,
+        );
+        assert!(result.is_ok());
+
+        let yaml = fs::read_to_string(tmp.path().join(".pre-commit-config.yaml")).unwrap();
+        assert!(!yaml.contains("hand-added-hook"));
+    }
+
+    fn init_git_repo(tmp: &Path) {
+        Command::new("git").args(["init", "-q"]).current_dir(tmp).output().unwrap();
+        Command::new("git")
+            .args(["config", "user.email", "test@example.com"])
+            .current_dir(tmp)
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["config", "user.name", "Test"])
+            .current_dir(tmp)
+            .output()
+            .unwrap();
+    }
+
+    fn commit_all(tmp: &Path, message: &str) {
+        Command::new("git").args(["add", "-A"]).current_dir(tmp).output().unwrap();
+        Command::new("git")
+            .args(["commit", "-q", "-m", message])
+            .current_dir(tmp)
+            .output()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_collect_changed_files_lists_modified_file_since_head() {
+        let tmp = tempdir().unwrap();
+        init_git_repo(tmp.path());
+        fs::write(tmp.path().join("a.txt"), "one\n").unwrap();
+        commit_all(tmp.path(), "initial");
+
+        fs::write(tmp.path().join("a.txt"), "two\n").unwrap();
+
+        let changed = collect_changed_files(tmp.path(), None).unwrap();
+        assert_eq!(changed, vec!["a.txt".to_string()]);
+    }
+
+    #[test]
+    fn test_collect_changed_files_prunes_deleted_paths() {
+        let tmp = tempdir().unwrap();
+        init_git_repo(tmp.path());
+        fs::write(tmp.path().join("a.txt"), "one\n").unwrap();
+        commit_all(tmp.path(), "initial");
+
+        fs::remove_file(tmp.path().join("a.txt")).unwrap();
+
+        let changed = collect_changed_files(tmp.path(), None).unwrap();
+        assert!(changed.is_empty());
+    }
+
+    #[test]
+    fn test_collect_changed_files_respects_since_ref() {
+        let tmp = tempdir().unwrap();
+        init_git_repo(tmp.path());
+        fs::write(tmp.path().join("a.txt"), "one\n").unwrap();
+        commit_all(tmp.path(), "initial");
+
+        let changed = collect_changed_files(tmp.path(), Some("HEAD")).unwrap();
+        assert!(changed.is_empty());
+    }
+
+    #[test]
+    fn test_collect_changed_files_includes_untracked_file() {
+        let tmp = tempdir().unwrap();
+        init_git_repo(tmp.path());
+        fs::write(tmp.path().join("a.txt"), "one\n").unwrap();
+        commit_all(tmp.path(), "initial");
+
+        fs::write(tmp.path().join("brand-new.txt"), "new\n").unwrap();
+
+        let changed = collect_changed_files(tmp.path(), None).unwrap();
+        assert_eq!(changed, vec!["brand-new.txt".to_string()]);
+    }
+
     #[test]
     fn test_resolve_directory_path_rejects_file() {
         let tmp = tempdir().unwrap();
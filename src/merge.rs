@@ -0,0 +1,189 @@
+//! Non-destructive merge of a freshly generated `.pre-commit-config.yaml`
+//! into an existing one.
+//!
+//! Unlike a plain overwrite, this preserves user-authored repos/hooks (and
+//! their order) structurally, only appending hooks or repos that the
+//! existing file is missing. Matching is done on repo URL + hook id, so
+//! hand-edited hook options (args, exclude patterns, etc.) on an entry that
+//! already exists are left untouched. This round-trips both documents
+//! through `serde_yaml::Value`, though, so any comments scattered through the
+//! existing file (other than its leading banner, which is restored from the
+//! freshly generated one) and its original key formatting are not preserved
+//! verbatim.
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+
+/// A single `repos:` entry. Any key beyond `repo`/`rev`/`hooks` is kept
+/// verbatim via `extra` so merging never drops fields this module doesn't
+/// know about (top-level keys such as `default_language_version` are kept
+/// the same way by `ConfigDoc`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct RepoEntry {
+    repo: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rev: Option<String>,
+    #[serde(default)]
+    hooks: Vec<Value>,
+    #[serde(flatten)]
+    extra: BTreeMap<String, Value>,
+}
+
+/// The top-level `.pre-commit-config.yaml` document.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct ConfigDoc {
+    #[serde(default)]
+    repos: Vec<RepoEntry>,
+    #[serde(flatten)]
+    extra: BTreeMap<String, Value>,
+}
+
+/// A summary of what a merge changed, for printing to the user.
+#[derive(Debug, Clone, Default)]
+pub struct MergeSummary {
+    /// `"<repo-url>#<hook-id>"` entries added because the existing file was
+    /// missing them.
+    pub added: Vec<String>,
+    /// `"<repo-url>#<hook-id>"` entries already present and left untouched.
+    pub preserved: Vec<String>,
+}
+
+/// Read a hook's `id` field out of its raw YAML mapping.
+fn hook_id(hook: &Value) -> Option<&str> {
+    hook.as_mapping()?.get("id")?.as_str()
+}
+
+/// Extract the leading `#`-prefixed banner a generated document opens with
+/// (prec-templ's "Generated by" header from `meta.j2`), stopping at the
+/// first non-comment line. Serializing through `serde_yaml::Value` drops
+/// comments, so the merged output's own banner is restored from here rather
+/// than lost entirely.
+pub(crate) fn leading_banner(yaml: &str) -> String {
+    let mut banner = String::new();
+    for line in yaml.lines() {
+        if line.trim_start().starts_with('#') {
+            banner.push_str(line);
+            banner.push('\n');
+        } else {
+            break;
+        }
+    }
+    banner
+}
+
+/// Merge `generated_yaml`'s repos/hooks into `existing_yaml`, preserving
+/// every user-authored entry and its order, and only adding what's missing.
+/// The existing file's own banner/comments are not preserved (`serde_yaml`
+/// doesn't round-trip them); the merged output's leading banner comes from
+/// `generated_yaml` instead, so it reflects the current version/timestamp.
+pub fn merge(existing_yaml: &str, generated_yaml: &str) -> Result<(String, MergeSummary), String> {
+    let mut existing: ConfigDoc = serde_yaml::from_str(existing_yaml)
+        .map_err(|e| format!("Failed to parse existing .pre-commit-config.yaml: {}", e))?;
+    let generated: ConfigDoc = serde_yaml::from_str(generated_yaml)
+        .map_err(|e| format!("Failed to parse generated configuration: {}", e))?;
+
+    let mut summary = MergeSummary::default();
+
+    for generated_repo in generated.repos {
+        match existing.repos.iter_mut().find(|r| r.repo == generated_repo.repo) {
+            Some(existing_repo) => {
+                for hook in generated_repo.hooks {
+                    let Some(id) = hook_id(&hook) else {
+                        continue;
+                    };
+                    let already_present = existing_repo
+                        .hooks
+                        .iter()
+                        .any(|h| hook_id(h) == Some(id));
+                    if already_present {
+                        summary
+                            .preserved
+                            .push(format!("{}#{}", generated_repo.repo, id));
+                    } else {
+                        summary.added.push(format!("{}#{}", generated_repo.repo, id));
+                        existing_repo.hooks.push(hook);
+                    }
+                }
+            }
+            None => {
+                for hook in &generated_repo.hooks {
+                    if let Some(id) = hook_id(hook) {
+                        summary.added.push(format!("{}#{}", generated_repo.repo, id));
+                    }
+                }
+                existing.repos.push(generated_repo);
+            }
+        }
+    }
+
+    let merged_yaml = serde_yaml::to_string(&existing)
+        .map_err(|e| format!("Failed to serialize merged configuration: {}", e))?;
+    let banner = leading_banner(generated_yaml);
+    let merged_yaml = format!("{}{}", banner, merged_yaml);
+    Ok((merged_yaml, summary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_adds_missing_hook_to_existing_repo() {
+        let existing = "repos:\n  - repo: https://github.com/psf/black\n    rev: 24.0.0\n    hooks:\n      - id: black\n";
+        let generated = "repos:\n  - repo: https://github.com/psf/black\n    rev: 24.1.0\n    hooks:\n      - id: black\n      - id: black-jupyter\n";
+
+        let (merged, summary) = merge(existing, generated).unwrap();
+        assert!(merged.contains("black-jupyter"));
+        assert_eq!(summary.added, vec!["https://github.com/psf/black#black-jupyter"]);
+        assert_eq!(summary.preserved, vec!["https://github.com/psf/black#black"]);
+    }
+
+    #[test]
+    fn test_merge_preserves_existing_hook_options() {
+        let existing = "repos:\n  - repo: https://github.com/psf/black\n    rev: 24.0.0\n    hooks:\n      - id: black\n        args: [--line-length=100]\n";
+        let generated = "repos:\n  - repo: https://github.com/psf/black\n    rev: 24.1.0\n    hooks:\n      - id: black\n";
+
+        let (merged, _) = merge(existing, generated).unwrap();
+        assert!(merged.contains("--line-length=100"));
+        assert!(merged.contains("24.0.0"));
+    }
+
+    #[test]
+    fn test_merge_appends_new_repo_without_touching_existing_order() {
+        let existing = "repos:\n  - repo: https://github.com/psf/black\n    rev: 24.0.0\n    hooks:\n      - id: black\n";
+        let generated = "repos:\n  - repo: https://github.com/pre-commit/pre-commit-hooks\n    rev: v4.6.0\n    hooks:\n      - id: check-yaml\n";
+
+        let (merged, summary) = merge(existing, generated).unwrap();
+        let doc: ConfigDoc = serde_yaml::from_str(&merged).unwrap();
+        assert_eq!(doc.repos[0].repo, "https://github.com/psf/black");
+        assert_eq!(doc.repos[1].repo, "https://github.com/pre-commit/pre-commit-hooks");
+        assert_eq!(
+            summary.added,
+            vec!["https://github.com/pre-commit/pre-commit-hooks#check-yaml"]
+        );
+    }
+
+    #[test]
+    fn test_merge_with_no_changes_reports_everything_preserved() {
+        let yaml = "repos:\n  - repo: https://github.com/psf/black\n    rev: 24.0.0\n    hooks:\n      - id: black\n";
+        let (_, summary) = merge(yaml, yaml).unwrap();
+        assert!(summary.added.is_empty());
+        assert_eq!(summary.preserved, vec!["https://github.com/psf/black#black"]);
+    }
+
+    #[test]
+    fn test_merge_restores_generated_banner() {
+        let existing = "repos:\n  - repo: https://github.com/psf/black\n    rev: 24.0.0\n    hooks:\n      - id: black\n";
+        let generated = "# Generated by prec-templ v1.2.3\nrepos:\n  - repo: https://github.com/psf/black\n    rev: 24.1.0\n    hooks:\n      - id: black\n";
+
+        let (merged, _) = merge(existing, generated).unwrap();
+        assert!(merged.starts_with("# Generated by prec-templ v1.2.3\n"));
+    }
+
+    #[test]
+    fn test_merge_rejects_unparseable_existing_yaml() {
+        let result = merge("repos: [unclosed", "repos: []\n");
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Project-local override file for pinning hook repos and filtering which
+//! auto-detected hooks get rendered.
+//!
+//! `.pre-commit-template.toml` lets a project pin a hook repo to a specific
+//! revision, force-add a repo that detection missed entirely, or
+//! include/exclude auto-detected hooks by a regex pattern matched against
+//! their hook id.
+
+use crate::merge::leading_banner;
+use crate::render::ExtraRepo;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_yaml::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Filename for the repo-pinning/include-exclude override file.
+const OVERRIDE_FILE: &str = ".pre-commit-template.toml";
+
+/// A single repo entry in the override file. If `url` matches one of the
+/// built-in hook repos, `rev` pins it; otherwise the repo is force-added
+/// with one hook, id'd `name`, that auto-detection would have missed.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RepoOverride {
+    pub name: String,
+    pub url: String,
+    #[serde(default)]
+    pub rev: Option<String>,
+}
+
+/// Parsed `.pre-commit-template.toml`.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct TemplateOverrides {
+    #[serde(default)]
+    pub repos: Vec<RepoOverride>,
+    /// Regex patterns; when non-empty, only hooks matching one of these are
+    /// kept (an allowlist applied on top of detection).
+    #[serde(default)]
+    pub included: Vec<String>,
+    /// Regex patterns for hooks to turn off regardless of detection.
+    #[serde(default)]
+    pub excluded: Vec<String>,
+}
+
+/// Read and parse `.pre-commit-template.toml` from `path`, if present.
+pub fn load(path: &Path) -> Result<Option<TemplateOverrides>, String> {
+    let override_path = path.join(OVERRIDE_FILE);
+    if !override_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&override_path)
+        .map_err(|e| format!("Failed to read {}: {}", OVERRIDE_FILE, e))?;
+    toml::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse {}: {}", OVERRIDE_FILE, e))
+}
+
+/// Match a hook id against a list of regex patterns, ignoring any pattern
+/// that fails to compile.
+fn matches_any(patterns: &[String], hook_id: &str) -> bool {
+    patterns
+        .iter()
+        .filter_map(|p| Regex::new(p).ok())
+        .any(|re| re.is_match(hook_id))
+}
+
+/// Apply `included`/`excluded` patterns to a fully rendered configuration's
+/// hook ids, dropping any hook that doesn't pass, and any repo left with no
+/// hooks at all. Filtering the rendered YAML rather than the config model
+/// means this covers every hook id the tool ever generates, including ones
+/// with no corresponding `PreCommitConfig` field (`ruff`, `prettier`,
+/// `eslint`, `golangci-lint`, `check-yaml`, ...). This round-trips through
+/// `serde_yaml::Value`, which drops comments, so the rendered banner is
+/// restored from `yaml`'s own leading comment lines rather than lost.
+pub fn apply_hook_filters(yaml: &str, overrides: &TemplateOverrides) -> Result<String, String> {
+    if overrides.included.is_empty() && overrides.excluded.is_empty() {
+        return Ok(yaml.to_string());
+    }
+
+    let mut doc: Value =
+        serde_yaml::from_str(yaml).map_err(|e| format!("Failed to parse rendered configuration: {}", e))?;
+
+    if let Some(repos) = doc.get_mut("repos").and_then(Value::as_sequence_mut) {
+        repos.retain_mut(|repo| match repo.get_mut("hooks").and_then(Value::as_sequence_mut) {
+            Some(hooks) => {
+                hooks.retain(|hook| {
+                    let id = hook.get("id").and_then(Value::as_str).unwrap_or("");
+                    let excluded = matches_any(&overrides.excluded, id);
+                    let allowed =
+                        overrides.included.is_empty() || matches_any(&overrides.included, id);
+                    allowed && !excluded
+                });
+                !hooks.is_empty()
+            }
+            None => true,
+        });
+    }
+
+    let filtered = serde_yaml::to_string(&doc)
+        .map_err(|e| format!("Failed to serialize filtered configuration: {}", e))?;
+    Ok(format!("{}{}", leading_banner(yaml), filtered))
+}
+
+/// Split the override file's `repos` into revision pins for repos the tool
+/// already renders (`known_revisions`' keys) and repos to force-add that
+/// detection missed entirely.
+pub fn split_repo_overrides(
+    overrides: &TemplateOverrides,
+    known_revisions: &HashMap<String, String>,
+) -> (HashMap<String, String>, Vec<ExtraRepo>) {
+    let mut pins = HashMap::new();
+    let mut extra_repos = Vec::new();
+
+    for repo in &overrides.repos {
+        if known_revisions.contains_key(&repo.url) {
+            if let Some(rev) = &repo.rev {
+                pins.insert(repo.url.clone(), rev.clone());
+            }
+        } else {
+            extra_repos.push(ExtraRepo {
+                name: repo.name.clone(),
+                url: repo.url.clone(),
+                rev: repo.rev.clone(),
+            });
+        }
+    }
+
+    (pins, extra_repos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_load_returns_none_when_file_missing() {
+        let tmp = tempdir().unwrap();
+        assert!(load(tmp.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_load_parses_override_file() {
+        let tmp = tempdir().unwrap();
+        fs::write(
+            tmp.path().join(OVERRIDE_FILE),
+            r#"
+included = []
+excluded = ["clippy"]
+
+[[repos]]
+name = "rustfmt"
+url = "https://github.com/pre-commit/pre-commit-hooks"
+rev = "v4.9.9"
+
+[[repos]]
+name = "custom-linter"
+url = "https://github.com/example/custom-linter"
+"#,
+        )
+        .unwrap();
+
+        let overrides = load(tmp.path()).unwrap().unwrap();
+        assert_eq!(overrides.excluded, vec!["clippy".to_string()]);
+        assert_eq!(overrides.repos.len(), 2);
+    }
+
+    const SAMPLE_YAML: &str = "repos:\n  - repo: https://github.com/astral-sh/ruff-pre-commit\n    rev: v0.4.4\n    hooks:\n      - id: ruff\n      - id: ruff-format\n  - repo: https://github.com/pre-commit/mirrors-prettier\n    rev: v3.1.0\n    hooks:\n      - id: prettier\n";
+
+    #[test]
+    fn test_apply_hook_filters_excludes_matching_hook_by_rendered_id() {
+        let overrides = TemplateOverrides {
+            excluded: vec!["ruff$".to_string()],
+            ..Default::default()
+        };
+        let filtered = apply_hook_filters(SAMPLE_YAML, &overrides).unwrap();
+        assert!(!filtered.contains("id: ruff\n"));
+        assert!(filtered.contains("ruff-format"));
+        assert!(filtered.contains("prettier"));
+    }
+
+    #[test]
+    fn test_apply_hook_filters_excludes_prettier() {
+        let overrides = TemplateOverrides {
+            excluded: vec!["prettier".to_string()],
+            ..Default::default()
+        };
+        let filtered = apply_hook_filters(SAMPLE_YAML, &overrides).unwrap();
+        assert!(!filtered.contains("prettier"));
+        assert!(!filtered.contains("mirrors-prettier"));
+        assert!(filtered.contains("ruff"));
+    }
+
+    #[test]
+    fn test_apply_hook_filters_included_is_an_allowlist() {
+        let overrides = TemplateOverrides {
+            included: vec!["ruff-format".to_string()],
+            ..Default::default()
+        };
+        let filtered = apply_hook_filters(SAMPLE_YAML, &overrides).unwrap();
+        assert!(filtered.contains("ruff-format"));
+        assert!(!filtered.contains("id: ruff\n"));
+        assert!(!filtered.contains("prettier"));
+    }
+
+    #[test]
+    fn test_apply_hook_filters_restores_leading_banner() {
+        let yaml = format!("# Generated by prec-templ v1.2.3\n{}", SAMPLE_YAML);
+        let overrides = TemplateOverrides {
+            excluded: vec!["prettier".to_string()],
+            ..Default::default()
+        };
+        let filtered = apply_hook_filters(&yaml, &overrides).unwrap();
+        assert!(filtered.starts_with("# Generated by prec-templ v1.2.3\n"));
+    }
+
+    #[test]
+    fn test_apply_hook_filters_is_a_no_op_with_no_patterns() {
+        let filtered = apply_hook_filters(SAMPLE_YAML, &TemplateOverrides::default()).unwrap();
+        assert_eq!(filtered, SAMPLE_YAML);
+    }
+
+    #[test]
+    fn test_split_repo_overrides_pins_known_repo() {
+        let mut known = HashMap::new();
+        known.insert(
+            "https://github.com/pre-commit/pre-commit-hooks".to_string(),
+            "v4.6.0".to_string(),
+        );
+        let overrides = TemplateOverrides {
+            repos: vec![RepoOverride {
+                name: "trailing-whitespace".to_string(),
+                url: "https://github.com/pre-commit/pre-commit-hooks".to_string(),
+                rev: Some("v4.9.9".to_string()),
+            }],
+            ..Default::default()
+        };
+
+        let (pins, extras) = split_repo_overrides(&overrides, &known);
+        assert_eq!(
+            pins.get("https://github.com/pre-commit/pre-commit-hooks"),
+            Some(&"v4.9.9".to_string())
+        );
+        assert!(extras.is_empty());
+    }
+
+    #[test]
+    fn test_split_repo_overrides_force_adds_unknown_repo() {
+        let known = HashMap::new();
+        let overrides = TemplateOverrides {
+            repos: vec![RepoOverride {
+                name: "custom-linter".to_string(),
+                url: "https://github.com/example/custom-linter".to_string(),
+                rev: Some("v1.0.0".to_string()),
+            }],
+            ..Default::default()
+        };
+
+        let (pins, extras) = split_repo_overrides(&overrides, &known);
+        assert!(pins.is_empty());
+        assert_eq!(extras.len(), 1);
+        assert_eq!(extras[0].name, "custom-linter");
+    }
+}
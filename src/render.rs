@@ -4,7 +4,8 @@
 
 use crate::config::PreCommitConfig;
 use chrono::Utc;
-use minijinja::{context, Environment};
+use minijinja::{context, Environment, Value};
+use std::collections::HashMap;
 
 /// Package version
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -15,79 +16,209 @@ const TEMPLATE_PYTHON: &str = include_str!("../templates/python.j2");
 const TEMPLATE_DOCKER: &str = include_str!("../templates/docker.j2");
 const TEMPLATE_JS: &str = include_str!("../templates/js.j2");
 const TEMPLATE_GO: &str = include_str!("../templates/go.j2");
+const TEMPLATE_RUST: &str = include_str!("../templates/rust.j2");
+const TEMPLATE_JAVA: &str = include_str!("../templates/java.j2");
+const TEMPLATE_RUBY: &str = include_str!("../templates/ruby.j2");
+const TEMPLATE_LUA: &str = include_str!("../templates/lua.j2");
+const TEMPLATE_DART: &str = include_str!("../templates/dart.j2");
+const TEMPLATE_PERL: &str = include_str!("../templates/perl.j2");
+const TEMPLATE_SHELL: &str = include_str!("../templates/shell.j2");
 const TEMPLATE_GITHUB_ACTIONS: &str = include_str!("../templates/github_actions.j2");
 const TEMPLATE_META: &str = include_str!("../templates/meta.j2");
 
+/// A pluggable hook pack: whether it's enabled, which template renders it,
+/// and how to build that template's context from the config and revisions.
+struct HookPack {
+    template_name: &'static str,
+    template_source: &'static str,
+    enabled: fn(&PreCommitConfig) -> bool,
+    context: fn(&PreCommitConfig, &HashMap<String, String>) -> Value,
+}
+
+/// The registry of optional hook packs. Adding a language is a data change
+/// here plus a new template and config flags, not edits scattered through
+/// `generate_hooks`/`render_config`.
+const HOOK_PACKS: &[HookPack] = &[
+    HookPack {
+        template_name: "python.j2",
+        template_source: TEMPLATE_PYTHON,
+        enabled: |c| c.python,
+        context: |c, revisions| {
+            context! {
+                uv_lock => c.uv_lock,
+                pyrefly_args => c.pyrefly_args,
+                rev => hook_rev(revisions, "https://github.com/astral-sh/ruff-pre-commit"),
+            }
+        },
+    },
+    HookPack {
+        template_name: "docker.j2",
+        template_source: TEMPLATE_DOCKER,
+        enabled: |c| c.docker,
+        context: |c, revisions| {
+            context! {
+                dockerfile_linting => c.dockerfile_linting,
+                dockerignore_check => c.dockerignore_check,
+                rev => hook_rev(revisions, "https://github.com/hadolint/hadolint"),
+            }
+        },
+    },
+    HookPack {
+        template_name: "github_actions.j2",
+        template_source: TEMPLATE_GITHUB_ACTIONS,
+        enabled: |c| c.github_actions,
+        context: |c, revisions| {
+            context! {
+                workflow_validation => c.workflow_validation,
+                security_scanning => c.security_scanning,
+                rev => hook_rev(revisions, "https://github.com/rhysd/actionlint"),
+            }
+        },
+    },
+    HookPack {
+        template_name: "js.j2",
+        template_source: TEMPLATE_JS,
+        enabled: |c| c.js,
+        context: |c, revisions| {
+            context! {
+                typescript => c.typescript,
+                jsx => c.jsx,
+                prettier_config => c.prettier_config,
+                eslint_config => c.eslint_config,
+                rev => hook_rev(revisions, "https://github.com/pre-commit/mirrors-prettier"),
+            }
+        },
+    },
+    HookPack {
+        template_name: "go.j2",
+        template_source: TEMPLATE_GO,
+        enabled: |c| c.go,
+        context: |c, revisions| {
+            context! {
+                go_critic => c.go_critic,
+                rev => hook_rev(revisions, "https://github.com/golangci/golangci-lint"),
+            }
+        },
+    },
+    HookPack {
+        template_name: "rust.j2",
+        template_source: TEMPLATE_RUST,
+        enabled: |c| c.rust && (c.rustfmt || c.clippy || c.cargo_check),
+        context: |c, _| {
+            context! {
+                rustfmt => c.rustfmt,
+                clippy => c.clippy,
+                cargo_check => c.cargo_check,
+            }
+        },
+    },
+    HookPack {
+        template_name: "java.j2",
+        template_source: TEMPLATE_JAVA,
+        enabled: |c| c.java && (c.java_format || c.checkstyle),
+        context: |c, _| {
+            context! {
+                java_format => c.java_format,
+                checkstyle => c.checkstyle,
+            }
+        },
+    },
+    HookPack {
+        template_name: "ruby.j2",
+        template_source: TEMPLATE_RUBY,
+        enabled: |c| c.ruby && c.rubocop,
+        context: |c, revisions| {
+            context! {
+                rubocop => c.rubocop,
+                rev => hook_rev(revisions, "https://github.com/rubocop/rubocop"),
+            }
+        },
+    },
+    HookPack {
+        template_name: "lua.j2",
+        template_source: TEMPLATE_LUA,
+        enabled: |c| c.lua && (c.luacheck || c.stylua),
+        context: |c, _| {
+            context! {
+                luacheck => c.luacheck,
+                stylua => c.stylua,
+            }
+        },
+    },
+    HookPack {
+        template_name: "dart.j2",
+        template_source: TEMPLATE_DART,
+        enabled: |c| c.dart,
+        context: |c, _| {
+            context! {
+                dart_analyze => c.dart_analyze,
+            }
+        },
+    },
+    HookPack {
+        template_name: "perl.j2",
+        template_source: TEMPLATE_PERL,
+        enabled: |c| c.perl && c.perlcritic,
+        context: |c, _| {
+            context! {
+                perlcritic => c.perlcritic,
+            }
+        },
+    },
+    HookPack {
+        template_name: "shell.j2",
+        template_source: TEMPLATE_SHELL,
+        enabled: |c| c.shell && (c.shellcheck || c.shfmt),
+        context: |c, _| {
+            context! {
+                shellcheck => c.shellcheck,
+                shfmt => c.shfmt,
+            }
+        },
+    },
+];
+
 /// Create a configured MiniJinja environment with all templates loaded.
 fn create_environment() -> Environment<'static> {
     let mut env = Environment::new();
     env.add_template("base.j2", TEMPLATE_BASE).unwrap();
-    env.add_template("python.j2", TEMPLATE_PYTHON).unwrap();
-    env.add_template("docker.j2", TEMPLATE_DOCKER).unwrap();
-    env.add_template("js.j2", TEMPLATE_JS).unwrap();
-    env.add_template("go.j2", TEMPLATE_GO).unwrap();
-    env.add_template("github_actions.j2", TEMPLATE_GITHUB_ACTIONS)
-        .unwrap();
     env.add_template("meta.j2", TEMPLATE_META).unwrap();
+    for pack in HOOK_PACKS {
+        env.add_template(pack.template_name, pack.template_source)
+            .unwrap();
+    }
     env
 }
 
-/// Generate hooks for a specific type.
-fn generate_hooks(
+/// Render the always-on base hooks.
+fn generate_base_hooks(
     env: &Environment,
-    hook_type: &str,
     config: &PreCommitConfig,
+    revisions: &HashMap<String, String>,
 ) -> Result<String, String> {
-    let template_name = match hook_type {
-        "base" => "base.j2",
-        "python" => "python.j2",
-        "docker" => "docker.j2",
-        "js" => "js.j2",
-        "go" => "go.j2",
-        "github_actions" => "github_actions.j2",
-        _ => return Err(format!("Unsupported hook type: {}", hook_type)),
+    let template = env.get_template("base.j2").map_err(|e| e.to_string())?;
+    let ctx = context! {
+        yaml => config.yaml_check,
+        json => config.json_check,
+        toml => config.toml_check,
+        xml => config.xml_check,
+        case_conflict => config.case_conflict,
+        executables => config.executables,
+        symlinks => config.symlinks,
+        python => config.python_base,
+        secrets_detection => config.secrets_detection,
+        dependency_audit => config.dependency_audit,
+        rev => hook_rev(revisions, "https://github.com/pre-commit/pre-commit-hooks"),
     };
-
-    let template = env.get_template(template_name).map_err(|e| e.to_string())?;
-
-    let ctx = match hook_type {
-        "base" => context! {
-            yaml => config.yaml_check,
-            json => config.json_check,
-            toml => config.toml_check,
-            xml => config.xml_check,
-            case_conflict => config.case_conflict,
-            executables => config.executables,
-            symlinks => config.symlinks,
-            python => config.python_base,
-        },
-        "python" => context! {
-            uv_lock => config.uv_lock,
-            pyrefly_args => config.pyrefly_args,
-        },
-        "docker" => context! {
-            dockerfile_linting => config.dockerfile_linting,
-            dockerignore_check => config.dockerignore_check,
-        },
-        "js" => context! {
-            typescript => config.typescript,
-            jsx => config.jsx,
-            prettier_config => config.prettier_config,
-            eslint_config => config.eslint_config,
-        },
-        "go" => context! {
-            go_critic => config.go_critic,
-        },
-        "github_actions" => context! {
-            workflow_validation => config.workflow_validation,
-            security_scanning => config.security_scanning,
-        },
-        _ => context! {},
-    };
-
     template.render(ctx).map_err(|e| e.to_string())
 }
 
+/// Look up a hook repo's pinned revision, falling back to `"main"` if the
+/// repo isn't present in the revision map.
+fn hook_rev<'a>(revisions: &'a HashMap<String, String>, repo_url: &str) -> &'a str {
+    revisions.get(repo_url).map(String::as_str).unwrap_or("main")
+}
+
 /// Indent each line of text by the specified number of spaces.
 fn indent(text: &str, spaces: usize) -> String {
     let prefix = " ".repeat(spaces);
@@ -103,30 +234,69 @@ fn indent(text: &str, spaces: usize) -> String {
         .join("\n")
 }
 
-/// Render the complete pre-commit configuration.
+/// A hook repo added via `.pre-commit-template.toml` that isn't one of the
+/// built-in hook packs (e.g. a project-specific local tool).
+#[derive(Debug, Clone)]
+pub struct ExtraRepo {
+    /// Hook id to register under this repo.
+    pub name: String,
+    pub url: String,
+    #[allow(dead_code)]
+    pub rev: Option<String>,
+}
+
+/// Render a force-added repo as a single-hook `repos:` entry.
+fn render_extra_repo(repo: &ExtraRepo) -> String {
+    format!(
+        "- repo: {}\n  rev: {}\n  hooks:\n    - id: {}\n",
+        repo.url,
+        repo.rev.as_deref().unwrap_or("main"),
+        repo.name
+    )
+}
+
+/// Render the complete pre-commit configuration using the offline default
+/// revision pins.
 pub fn render_config(config: &PreCommitConfig) -> Result<String, String> {
+    render_config_with_revisions(config, &crate::revisions::default_revisions())
+}
+
+/// Render the complete pre-commit configuration, pinning external hook repos
+/// to the given revision map (e.g. the result of `--autoupdate`).
+pub fn render_config_with_revisions(
+    config: &PreCommitConfig,
+    revisions: &HashMap<String, String>,
+) -> Result<String, String> {
+    render_config_with_revisions_and_extras(config, revisions, &[])
+}
+
+/// Render the complete pre-commit configuration, additionally appending any
+/// `extra_repos` force-added via `.pre-commit-template.toml` for repos that
+/// auto-detection missed entirely.
+pub fn render_config_with_revisions_and_extras(
+    config: &PreCommitConfig,
+    revisions: &HashMap<String, String>,
+    extra_repos: &[ExtraRepo],
+) -> Result<String, String> {
     let env = create_environment();
     let mut hooks_content = Vec::new();
 
     // Always add base hooks
-    let base_content = generate_hooks(&env, "base", config)?;
-    hooks_content.push(base_content);
+    hooks_content.push(generate_base_hooks(&env, config, revisions)?);
 
-    // Optional hooks
-    if config.python {
-        hooks_content.push(generate_hooks(&env, "python", config)?);
-    }
-    if config.docker {
-        hooks_content.push(generate_hooks(&env, "docker", config)?);
+    // Optional hooks, driven by the hook pack registry
+    for pack in HOOK_PACKS {
+        if (pack.enabled)(config) {
+            let template = env
+                .get_template(pack.template_name)
+                .map_err(|e| e.to_string())?;
+            let ctx = (pack.context)(config, revisions);
+            hooks_content.push(template.render(ctx).map_err(|e| e.to_string())?);
+        }
     }
-    if config.github_actions {
-        hooks_content.push(generate_hooks(&env, "github_actions", config)?);
-    }
-    if config.js {
-        hooks_content.push(generate_hooks(&env, "js", config)?);
-    }
-    if config.go {
-        hooks_content.push(generate_hooks(&env, "go", config)?);
+
+    for repo in extra_repos {
+        hooks_content.push(render_extra_repo(repo));
     }
 
     let combined_content = hooks_content.join("\n\n");
@@ -159,6 +329,29 @@ pub fn render_config(config: &PreCommitConfig) -> Result<String, String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_render_uses_pinned_revision() {
+        let config = PreCommitConfig {
+            python: true,
+            python_base: true,
+            ..Default::default()
+        };
+        let result = render_config(&config).unwrap();
+        assert!(result.contains("v0.4.4"));
+    }
+
+    #[test]
+    fn test_render_with_revisions_override() {
+        let mut revisions = HashMap::new();
+        revisions.insert(
+            "https://github.com/pre-commit/pre-commit-hooks".to_string(),
+            "v9.9.9".to_string(),
+        );
+        let result =
+            render_config_with_revisions(&PreCommitConfig::default(), &revisions).unwrap();
+        assert!(result.contains("v9.9.9"));
+    }
+
     #[test]
     fn test_render_minimal_config() {
         let config = PreCommitConfig::default();
@@ -198,6 +391,170 @@ mod tests {
         assert!(yaml.contains("python: python3.11"));
     }
 
+    #[test]
+    fn test_render_rust_config() {
+        let config = PreCommitConfig {
+            rust: true,
+            rustfmt: true,
+            clippy: true,
+            cargo_check: true,
+            ..Default::default()
+        };
+        let result = render_config(&config);
+        assert!(result.is_ok());
+        let yaml = result.unwrap();
+        assert!(yaml.contains("rustfmt"));
+        assert!(yaml.contains("clippy"));
+        assert!(yaml.contains("cargo-check"));
+    }
+
+    #[test]
+    fn test_render_java_config() {
+        let config = PreCommitConfig {
+            java: true,
+            java_format: true,
+            checkstyle: true,
+            ..Default::default()
+        };
+        let result = render_config(&config);
+        assert!(result.is_ok());
+        let yaml = result.unwrap();
+        assert!(yaml.contains("google-java-format"));
+        assert!(yaml.contains("checkstyle"));
+    }
+
+    #[test]
+    fn test_render_shell_config() {
+        let config = PreCommitConfig {
+            shell: true,
+            shellcheck: true,
+            shfmt: true,
+            ..Default::default()
+        };
+        let result = render_config(&config);
+        assert!(result.is_ok());
+        let yaml = result.unwrap();
+        assert!(yaml.contains("shellcheck"));
+        assert!(yaml.contains("shfmt"));
+    }
+
+    #[test]
+    fn test_render_ruby_config() {
+        let config = PreCommitConfig {
+            ruby: true,
+            rubocop: true,
+            ..Default::default()
+        };
+        let result = render_config(&config);
+        assert!(result.is_ok());
+        assert!(result.unwrap().contains("rubocop"));
+    }
+
+    #[test]
+    fn test_render_ruby_omits_pack_when_rubocop_disabled() {
+        let config = PreCommitConfig {
+            ruby: true,
+            rubocop: false,
+            ..Default::default()
+        };
+        let yaml = render_config(&config).unwrap();
+        assert!(!yaml.contains("rubocop"));
+    }
+
+    #[test]
+    fn test_render_perl_omits_pack_when_perlcritic_disabled() {
+        let config = PreCommitConfig {
+            perl: true,
+            perlcritic: false,
+            ..Default::default()
+        };
+        let yaml = render_config(&config).unwrap();
+        assert!(!yaml.contains("perlcritic"));
+    }
+
+    #[test]
+    fn test_render_lua_omits_pack_when_all_sub_hooks_disabled() {
+        let config = PreCommitConfig {
+            lua: true,
+            luacheck: false,
+            stylua: false,
+            ..Default::default()
+        };
+        let yaml = render_config(&config).unwrap();
+        assert!(!yaml.contains("luacheck"));
+        assert!(!yaml.contains("stylua"));
+    }
+
+    #[test]
+    fn test_render_shell_omits_pack_when_all_sub_hooks_disabled() {
+        let config = PreCommitConfig {
+            shell: true,
+            shellcheck: false,
+            shfmt: false,
+            ..Default::default()
+        };
+        let yaml = render_config(&config).unwrap();
+        assert!(!yaml.contains("shellcheck"));
+        assert!(!yaml.contains("shfmt"));
+    }
+
+    #[test]
+    fn test_render_rust_omits_pack_when_all_sub_hooks_disabled() {
+        let config = PreCommitConfig {
+            rust: true,
+            rustfmt: false,
+            clippy: false,
+            cargo_check: false,
+            ..Default::default()
+        };
+        let yaml = render_config(&config).unwrap();
+        assert!(!yaml.contains("rustfmt"));
+        assert!(!yaml.contains("clippy"));
+        assert!(!yaml.contains("cargo-check"));
+    }
+
+    #[test]
+    fn test_render_java_omits_pack_when_all_sub_hooks_disabled() {
+        let config = PreCommitConfig {
+            java: true,
+            java_format: false,
+            checkstyle: false,
+            ..Default::default()
+        };
+        let yaml = render_config(&config).unwrap();
+        assert!(!yaml.contains("google-java-format"));
+        assert!(!yaml.contains("checkstyle"));
+    }
+
+    #[test]
+    fn test_render_security_profile_fields_reach_base_hooks() {
+        let config = PreCommitConfig {
+            secrets_detection: true,
+            dependency_audit: true,
+            ..Default::default()
+        };
+        let result = render_config(&config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_render_with_extra_repo() {
+        let extra = vec![ExtraRepo {
+            name: "my-custom-hook".to_string(),
+            url: "https://github.com/example/custom-hook".to_string(),
+            rev: Some("v1.0.0".to_string()),
+        }];
+        let result = render_config_with_revisions_and_extras(
+            &PreCommitConfig::default(),
+            &HashMap::new(),
+            &extra,
+        )
+        .unwrap();
+        assert!(result.contains("https://github.com/example/custom-hook"));
+        assert!(result.contains("my-custom-hook"));
+        assert!(result.contains("v1.0.0"));
+    }
+
     #[test]
     fn test_indent() {
         let text = "line1\nline2\nline3";
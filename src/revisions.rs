@@ -0,0 +1,141 @@
+//! Pinned revisions for externally hosted pre-commit hook repos.
+//!
+//! Templates render a hook repo's `rev:` from this map instead of a
+//! hardcoded tag, so generated configs stay deterministic offline and can be
+//! refreshed on demand via `--autoupdate`.
+
+use std::collections::HashMap;
+use std::process::Command;
+
+/// Offline default revision pins, keyed by hook repo URL.
+const PINNED_REVISIONS: &[(&str, &str)] = &[
+    ("https://github.com/pre-commit/pre-commit-hooks", "v4.6.0"),
+    ("https://github.com/astral-sh/ruff-pre-commit", "v0.4.4"),
+    ("https://github.com/pre-commit/mirrors-prettier", "v3.1.0"),
+    ("https://github.com/golangci/golangci-lint", "v1.59.0"),
+    ("https://github.com/hadolint/hadolint", "v2.12.0"),
+    ("https://github.com/rhysd/actionlint", "v1.7.1"),
+];
+
+/// Build the offline default revision map.
+pub fn default_revisions() -> HashMap<String, String> {
+    PINNED_REVISIONS
+        .iter()
+        .map(|(url, rev)| (url.to_string(), rev.to_string()))
+        .collect()
+}
+
+/// Query each repo's latest tag over the network and return a refreshed
+/// revision map plus a `(repo_url, old_rev, new_rev)` summary of changes.
+pub fn autoupdate(
+    current: &HashMap<String, String>,
+) -> (HashMap<String, String>, Vec<(String, String, String)>) {
+    let mut updated = current.clone();
+    let mut changes = Vec::new();
+
+    for (url, rev) in current {
+        if let Some(latest) = latest_tag(url) {
+            if &latest != rev {
+                changes.push((url.clone(), rev.clone(), latest.clone()));
+                updated.insert(url.clone(), latest);
+            }
+        }
+    }
+
+    (updated, changes)
+}
+
+/// Look up the most recent tag for a repo via `git ls-remote --tags`.
+///
+/// Returns `None` if `git` is unavailable or the remote can't be reached,
+/// so a flaky network falls back to the existing pin rather than erroring.
+fn latest_tag(repo_url: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["ls-remote", "--tags", "--refs", repo_url])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .filter_map(|line| line.rsplit("refs/tags/").next())
+        .filter_map(|tag| parse_version(tag).map(|version| (version, tag.to_string())))
+        .max_by_key(|(version, _)| *version)
+        .map(|(_, tag)| tag)
+}
+
+/// Parse a tag like `v1.59.0` into a `(major, minor, patch)` tuple for
+/// version-ordered comparison, ignoring a leading `v` and any pre-release
+/// suffix on the patch component (`0-rc1` -> `0`). Returns `None` for refs
+/// that aren't version tags at all, so they're excluded from "latest".
+fn parse_version(tag: &str) -> Option<(u64, u64, u64)> {
+    let trimmed = tag.trim_start_matches('v');
+    let mut parts = trimmed.split('.');
+
+    let major = parts.next()?.parse::<u64>().ok()?;
+    let minor = parts
+        .next()
+        .map(|p| p.parse::<u64>().ok())
+        .unwrap_or(Some(0))?;
+    let patch = parts
+        .next()
+        .map(|p| {
+            let digits: String = p.chars().take_while(|c| c.is_ascii_digit()).collect();
+            if digits.is_empty() {
+                Some(0)
+            } else {
+                digits.parse::<u64>().ok()
+            }
+        })
+        .unwrap_or(Some(0))?;
+
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_revisions_has_pinned_entries() {
+        let revisions = default_revisions();
+        assert_eq!(
+            revisions.get("https://github.com/pre-commit/pre-commit-hooks"),
+            Some(&"v4.6.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_default_revisions_matches_static_table_len() {
+        assert_eq!(default_revisions().len(), PINNED_REVISIONS.len());
+    }
+
+    #[test]
+    fn test_parse_version_orders_by_value_not_lexically() {
+        assert!(parse_version("v4.10.0") > parse_version("v4.6.0"));
+    }
+
+    #[test]
+    fn test_parse_version_strips_leading_v() {
+        assert_eq!(parse_version("v1.59.0"), Some((1, 59, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_handles_missing_patch() {
+        assert_eq!(parse_version("v2.1"), Some((2, 1, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_strips_prerelease_suffix() {
+        assert_eq!(parse_version("v1.2.0-rc1"), Some((1, 2, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_rejects_non_version_refs() {
+        assert_eq!(parse_version("latest"), None);
+        assert_eq!(parse_version("release-candidate"), None);
+    }
+}
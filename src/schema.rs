@@ -0,0 +1,51 @@
+//! JSON Schema generation for the `schema` subcommand.
+//!
+//! Combines a schema for `TemplateOverrides` (the `.pre-commit-template.toml`
+//! override file editors need to validate/autocomplete) with one for
+//! `PreCommitConfig` (the full set of detectable technologies and hook
+//! options), both derived straight from the structs `overrides::load` and
+//! `discover::discover_config` populate so this can't drift from the real
+//! model.
+
+use crate::config::PreCommitConfig;
+use crate::overrides::TemplateOverrides;
+use schemars::schema_for;
+
+/// Render the combined JSON Schema document, pretty-printed, for stdout.
+pub fn document() -> Result<String, String> {
+    let mut root = schema_for!(TemplateOverrides);
+    let config_schema = schema_for!(PreCommitConfig);
+
+    root.definitions
+        .insert("PreCommitConfig".to_string(), config_schema.schema.into());
+    root.definitions.extend(config_schema.definitions);
+
+    serde_json::to_string_pretty(&root).map_err(|e| format!("Failed to serialize schema: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_includes_override_file_fields() {
+        let doc = document().unwrap();
+        assert!(doc.contains("TemplateOverrides") || doc.contains("\"repos\""));
+        assert!(doc.contains("RepoOverride"));
+    }
+
+    #[test]
+    fn test_document_includes_detectable_config_fields() {
+        let doc = document().unwrap();
+        assert!(doc.contains("PreCommitConfig"));
+        assert!(doc.contains("clippy"));
+        assert!(doc.contains("python_version"));
+    }
+
+    #[test]
+    fn test_document_is_valid_json() {
+        let doc = document().unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&doc).unwrap();
+        assert!(parsed.is_object());
+    }
+}
@@ -2,9 +2,9 @@
 //!
 //! Provides Rich-like terminal output using console, dialoguer, and indicatif.
 
-use crate::config::PreCommitConfig;
+use crate::config::{PreCommitConfig, Profile};
 use console::{style, Term};
-use dialoguer::{Confirm, Input};
+use dialoguer::{Confirm, Input, Select};
 use indicatif::{ProgressBar, ProgressStyle};
 use std::time::Duration;
 
@@ -42,10 +42,13 @@ pub fn display_detected_technologies(config: &PreCommitConfig) {
     if config.js {
         let mut details = Vec::new();
         if config.typescript {
-            details.push("TypeScript");
+            details.push("TypeScript".to_string());
         }
         if config.jsx {
-            details.push("JSX/React");
+            details.push("JSX/React".to_string());
+        }
+        if let Some(ref package_manager) = config.package_manager {
+            details.push(package_manager.clone());
         }
         let detail_str = if details.is_empty() {
             "Basic JavaScript".to_string()
@@ -66,6 +69,65 @@ pub fn display_detected_technologies(config: &PreCommitConfig) {
         );
     }
 
+    if config.rust {
+        let version_info = config
+            .cargo_version
+            .as_deref()
+            .unwrap_or("No version specified");
+        println!(
+            "│  {} Rust            {}  │",
+            style("✓").green(),
+            style(format!("({})", version_info)).dim()
+        );
+    }
+
+    if config.java {
+        let version_info = config
+            .maven_version
+            .as_deref()
+            .unwrap_or("No version specified");
+        println!(
+            "│  {} Java            {}  │",
+            style("✓").green(),
+            style(format!("({})", version_info)).dim()
+        );
+    }
+
+    if config.ruby {
+        println!(
+            "│  {} Ruby                                         │",
+            style("✓").green()
+        );
+    }
+
+    if config.lua {
+        println!(
+            "│  {} Lua                                          │",
+            style("✓").green()
+        );
+    }
+
+    if config.dart {
+        println!(
+            "│  {} Dart                                         │",
+            style("✓").green()
+        );
+    }
+
+    if config.perl {
+        println!(
+            "│  {} Perl                                         │",
+            style("✓").green()
+        );
+    }
+
+    if config.shell {
+        println!(
+            "│  {} Shell                                        │",
+            style("✓").green()
+        );
+    }
+
     // Infrastructure
     if config.docker {
         println!(
@@ -112,6 +174,81 @@ pub fn display_detected_technologies(config: &PreCommitConfig) {
     let _ = term.flush();
 }
 
+/// Print a summary of `.pre-commit-template.toml` entries, so it's clear
+/// which parts of the final config came from the override file rather than
+/// auto-detection.
+pub fn display_overrides(overrides: &crate::overrides::TemplateOverrides) {
+    if overrides.repos.is_empty() && overrides.included.is_empty() && overrides.excluded.is_empty()
+    {
+        return;
+    }
+
+    println!(
+        "{}",
+        style("From .pre-commit-template.toml:").bold().yellow()
+    );
+    let known_revisions = crate::revisions::default_revisions();
+    for repo in &overrides.repos {
+        if !known_revisions.contains_key(&repo.url) {
+            println!("  • added {} ({})", repo.url, repo.name);
+        } else if let Some(rev) = &repo.rev {
+            println!("  • pinned {} @ {}", repo.url, rev);
+        }
+    }
+    if !overrides.included.is_empty() {
+        println!("  • included only: {}", overrides.included.join(", "));
+    }
+    if !overrides.excluded.is_empty() {
+        println!("  • excluded: {}", overrides.excluded.join(", "));
+    }
+    println!();
+}
+
+/// Present the generation profiles with their purpose strings and return the
+/// one the user picks, defaulting to `Standard`.
+pub fn select_profile() -> Profile {
+    println!("{}", style("Select a generation profile").bold());
+    let items: Vec<String> = Profile::ALL
+        .iter()
+        .map(|p| format!("{} - {}", p, p.purpose()))
+        .collect();
+
+    let standard_index = Profile::ALL
+        .iter()
+        .position(|p| *p == Profile::Standard)
+        .unwrap_or(0);
+
+    let selection = Select::new()
+        .items(&items)
+        .default(standard_index)
+        .interact()
+        .unwrap_or(standard_index);
+
+    Profile::ALL[selection]
+}
+
+/// Print a summary of which hooks a non-destructive merge added vs. left
+/// untouched in an existing `.pre-commit-config.yaml`.
+pub fn display_merge_summary(summary: &crate::merge::MergeSummary) {
+    if summary.added.is_empty() && summary.preserved.is_empty() {
+        return;
+    }
+
+    println!(
+        "{}",
+        style("Merging into existing .pre-commit-config.yaml:")
+            .bold()
+            .yellow()
+    );
+    for entry in &summary.added {
+        println!("  {} added {}", style("+").green(), entry);
+    }
+    for entry in &summary.preserved {
+        println!("  {} preserved {}", style("=").dim(), entry);
+    }
+    println!();
+}
+
 /// Create a spinner for progress indication.
 pub fn create_spinner(message: &str) -> ProgressBar {
     let pb = ProgressBar::new_spinner();
@@ -200,8 +337,16 @@ pub fn ask_user_preferences(detected_config: PreCommitConfig) -> PreCommitConfig
     // JavaScript section
     if detected_config.js {
         println!("{}", style("JavaScript/TypeScript Hooks").bold());
+        let manager_suffix = detected_config
+            .package_manager
+            .as_deref()
+            .map(|m| format!(" (detected {})", m))
+            .unwrap_or_default();
         config.js = Confirm::new()
-            .with_prompt("Include JavaScript/TypeScript hooks (Prettier + ESLint)?")
+            .with_prompt(format!(
+                "Include JavaScript/TypeScript hooks (Prettier + ESLint){}?",
+                manager_suffix
+            ))
             .default(detected_config.js)
             .interact()
             .unwrap_or(detected_config.js);
@@ -241,6 +386,169 @@ pub fn ask_user_preferences(detected_config: PreCommitConfig) -> PreCommitConfig
         println!();
     }
 
+    // Rust section
+    if detected_config.rust {
+        println!("{}", style("Rust Hooks").bold());
+        config.rust = Confirm::new()
+            .with_prompt("Include Rust hooks (rustfmt + clippy + cargo check)?")
+            .default(detected_config.rust)
+            .interact()
+            .unwrap_or(detected_config.rust);
+
+        if config.rust {
+            config.rustfmt = Confirm::new()
+                .with_prompt("Include rustfmt check?")
+                .default(detected_config.rustfmt)
+                .interact()
+                .unwrap_or(detected_config.rustfmt);
+
+            config.clippy = Confirm::new()
+                .with_prompt("Include clippy linting?")
+                .default(detected_config.clippy)
+                .interact()
+                .unwrap_or(detected_config.clippy);
+
+            config.cargo_check = Confirm::new()
+                .with_prompt("Include cargo check?")
+                .default(detected_config.cargo_check)
+                .interact()
+                .unwrap_or(detected_config.cargo_check);
+        }
+        println!();
+    }
+
+    // Java section
+    if detected_config.java {
+        println!("{}", style("Java Hooks").bold());
+        config.java = Confirm::new()
+            .with_prompt("Include Java hooks (google-java-format + checkstyle)?")
+            .default(detected_config.java)
+            .interact()
+            .unwrap_or(detected_config.java);
+
+        if config.java {
+            config.java_format = Confirm::new()
+                .with_prompt("Include google-java-format?")
+                .default(detected_config.java_format)
+                .interact()
+                .unwrap_or(detected_config.java_format);
+
+            config.checkstyle = Confirm::new()
+                .with_prompt("Include checkstyle linting?")
+                .default(detected_config.checkstyle)
+                .interact()
+                .unwrap_or(detected_config.checkstyle);
+        }
+        println!();
+    }
+
+    // Ruby section
+    if detected_config.ruby {
+        println!("{}", style("Ruby Hooks").bold());
+        config.ruby = Confirm::new()
+            .with_prompt("Include Ruby hooks (RuboCop)?")
+            .default(detected_config.ruby)
+            .interact()
+            .unwrap_or(detected_config.ruby);
+
+        if config.ruby {
+            config.rubocop = Confirm::new()
+                .with_prompt("Include RuboCop linting?")
+                .default(detected_config.rubocop)
+                .interact()
+                .unwrap_or(detected_config.rubocop);
+        }
+        println!();
+    }
+
+    // Lua section
+    if detected_config.lua {
+        println!("{}", style("Lua Hooks").bold());
+        config.lua = Confirm::new()
+            .with_prompt("Include Lua hooks (luacheck + StyLua)?")
+            .default(detected_config.lua)
+            .interact()
+            .unwrap_or(detected_config.lua);
+
+        if config.lua {
+            config.luacheck = Confirm::new()
+                .with_prompt("Include luacheck linting?")
+                .default(detected_config.luacheck)
+                .interact()
+                .unwrap_or(detected_config.luacheck);
+
+            config.stylua = Confirm::new()
+                .with_prompt("Include StyLua formatting?")
+                .default(detected_config.stylua)
+                .interact()
+                .unwrap_or(detected_config.stylua);
+        }
+        println!();
+    }
+
+    // Dart section
+    if detected_config.dart {
+        println!("{}", style("Dart Hooks").bold());
+        config.dart = Confirm::new()
+            .with_prompt("Include Dart hooks (dart format + dart analyze)?")
+            .default(detected_config.dart)
+            .interact()
+            .unwrap_or(detected_config.dart);
+
+        if config.dart {
+            config.dart_analyze = Confirm::new()
+                .with_prompt("Include dart analyze?")
+                .default(detected_config.dart_analyze)
+                .interact()
+                .unwrap_or(detected_config.dart_analyze);
+        }
+        println!();
+    }
+
+    // Perl section
+    if detected_config.perl {
+        println!("{}", style("Perl Hooks").bold());
+        config.perl = Confirm::new()
+            .with_prompt("Include Perl hooks (perlcritic)?")
+            .default(detected_config.perl)
+            .interact()
+            .unwrap_or(detected_config.perl);
+
+        if config.perl {
+            config.perlcritic = Confirm::new()
+                .with_prompt("Include perlcritic linting?")
+                .default(detected_config.perlcritic)
+                .interact()
+                .unwrap_or(detected_config.perlcritic);
+        }
+        println!();
+    }
+
+    // Shell section
+    if detected_config.shell {
+        println!("{}", style("Shell Hooks").bold());
+        config.shell = Confirm::new()
+            .with_prompt("Include shell hooks (ShellCheck + shfmt)?")
+            .default(detected_config.shell)
+            .interact()
+            .unwrap_or(detected_config.shell);
+
+        if config.shell {
+            config.shellcheck = Confirm::new()
+                .with_prompt("Include ShellCheck linting?")
+                .default(detected_config.shellcheck)
+                .interact()
+                .unwrap_or(detected_config.shellcheck);
+
+            config.shfmt = Confirm::new()
+                .with_prompt("Include shfmt formatting?")
+                .default(detected_config.shfmt)
+                .interact()
+                .unwrap_or(detected_config.shfmt);
+        }
+        println!();
+    }
+
     // Docker section
     if detected_config.docker {
         println!("{}", style("Docker Hooks").bold());